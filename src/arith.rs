@@ -0,0 +1,153 @@
+// src/arith.rs
+// NovaLang için aritmetik, karşılaştırma ve tekli operatörler (iki motor ortak kullanır)
+
+use crate::error::{NovaError, NovaErrorKind};
+use crate::runtime::type_name;
+use crate::vm::Value;
+use std::cmp::Ordering;
+
+// `"ab" * n` ile kurulabilecek en uzun string (bayt)
+const MAX_STRING_BYTES: usize = 1 << 30;
+
+/// İkili operatörü uygular. Int ve Float karışınca sonuç Float olur; Int işlemleri
+/// taşmayı denetler. `&&`/`||` kısa devre yaptığı için burada değil motorlarda ele alınır.
+/// Dönen hatanın konumu yoktur; çağıran ifadenin aralığını ekler.
+pub fn binary(op: &str, left: &Value, right: &Value) -> Result<Value, NovaError> {
+    match op {
+        "+" => add(left, right),
+        "-" => numeric(op, left, right),
+        "*" => multiply(left, right),
+        "/" | "%" => divide(op, left, right),
+        "==" => Ok(Value::Bool(crate::runtime::values_equal(left, right))),
+        "!=" => Ok(Value::Bool(!crate::runtime::values_equal(left, right))),
+        "<" | ">" | "<=" | ">=" => {
+            // NaN hiçbir sayıdan küçük, büyük ya da ona eşit değildir
+            let result = match compare(left, right)? {
+                None => false,
+                Some(ordering) => match op {
+                    "<" => ordering == Ordering::Less,
+                    ">" => ordering == Ordering::Greater,
+                    "<=" => ordering != Ordering::Greater,
+                    _ => ordering != Ordering::Less,
+                },
+            };
+            Ok(Value::Bool(result))
+        }
+        _ => Err(NovaError::new(NovaErrorKind::RuntimeError, &format!("Bilinmeyen operatör: {}", op), 0, 0, None)),
+    }
+}
+
+pub fn unary(op: &str, val: &Value) -> Result<Value, NovaError> {
+    match (op, val) {
+        ("!", _) => Ok(Value::Bool(!val.is_truthy())),
+        ("-", Value::Int(i)) => i.checked_neg().map(Value::Int).ok_or_else(|| overflow(&format!("-({})", i))),
+        ("-", Value::Float(f)) => Ok(Value::Float(-f)),
+        _ => Err(type_error(&format!("'{}' değerinin negatifi alınamaz", type_name(val)))),
+    }
+}
+
+fn add(left: &Value, right: &Value) -> Result<Value, NovaError> {
+    match (left, right) {
+        (Value::String(a), Value::String(b)) => Ok(Value::String(format!("{}{}", a, b))),
+        // Birleştirme yeni bir liste üretir; işlenenler değişmez
+        (Value::List(a), Value::List(b)) => {
+            let mut items = a.borrow().clone();
+            items.extend(b.borrow().iter().cloned());
+            Ok(Value::list(items))
+        }
+        _ => numeric("+", left, right),
+    }
+}
+
+fn multiply(left: &Value, right: &Value) -> Result<Value, NovaError> {
+    match (left, right) {
+        (Value::String(s), Value::Int(n)) | (Value::Int(n), Value::String(s)) => {
+            let count = usize::try_from(*n).map_err(|_| type_error("String tekrar sayısı negatif olamaz"))?;
+            match s.len().checked_mul(count) {
+                Some(size) if size <= MAX_STRING_BYTES => Ok(Value::String(s.repeat(count))),
+                _ => Err(NovaError::new(NovaErrorKind::RuntimeError, "String tekrarı çok büyük", 0, 0, None)),
+            }
+        }
+        _ => numeric("*", left, right),
+    }
+}
+
+fn divide(op: &str, left: &Value, right: &Value) -> Result<Value, NovaError> {
+    let zero = match right {
+        Value::Int(0) => true,
+        Value::Float(f) => *f == 0.0,
+        _ => false,
+    };
+    if zero && matches!(left, Value::Int(_) | Value::Float(_)) {
+        return Err(NovaError::new(NovaErrorKind::RuntimeError, "Sıfıra bölme", 0, 0, None));
+    }
+    numeric(op, left, right)
+}
+
+/// Sayısal işlem: iki Int için taşma denetimli tamsayı aritmetiği (`/` sıfıra doğru
+/// keser), en az bir Float varsa kayan nokta aritmetiği.
+fn numeric(op: &str, left: &Value, right: &Value) -> Result<Value, NovaError> {
+    match (left, right) {
+        (Value::Int(a), Value::Int(b)) => {
+            let result = match op {
+                "+" => a.checked_add(*b),
+                "-" => a.checked_sub(*b),
+                "*" => a.checked_mul(*b),
+                "/" => a.checked_div(*b),
+                _ => a.checked_rem(*b),
+            };
+            result.map(Value::Int).ok_or_else(|| overflow(&format!("{} {} {}", a, op, b)))
+        }
+        (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => {
+            let (a, b) = (as_float(left), as_float(right));
+            let result = match op {
+                "+" => a + b,
+                "-" => a - b,
+                "*" => a * b,
+                "/" => a / b,
+                _ => a % b,
+            };
+            Ok(Value::Float(result))
+        }
+        _ => {
+            let verb = match op {
+                "+" => "toplanamaz",
+                "-" => "çıkarılamaz",
+                "*" => "çarpılamaz",
+                "/" => "bölünemez",
+                _ => "arasında mod alınamaz",
+            };
+            Err(type_error(&format!("'{}' ile '{}' {}", type_name(left), type_name(right), verb)))
+        }
+    }
+}
+
+/// Sayılar değerce, string'ler sözlük sırasıyla karşılaştırılır.
+fn compare(left: &Value, right: &Value) -> Result<Option<Ordering>, NovaError> {
+    let ordering = match (left, right) {
+        (Value::Int(a), Value::Int(b)) => Some(a.cmp(b)),
+        (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => as_float(left).partial_cmp(&as_float(right)),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => {
+            let message = format!("'{}' ile '{}' karşılaştırılamaz", type_name(left), type_name(right));
+            return Err(type_error(&message));
+        }
+    };
+    Ok(ordering)
+}
+
+fn as_float(val: &Value) -> f64 {
+    match val {
+        Value::Int(i) => *i as f64,
+        Value::Float(f) => *f,
+        _ => unreachable!("sayı bekleniyordu"),
+    }
+}
+
+fn type_error(message: &str) -> NovaError {
+    NovaError::new(NovaErrorKind::TypeError, message, 0, 0, None)
+}
+
+fn overflow(expr: &str) -> NovaError {
+    NovaError::new(NovaErrorKind::RuntimeError, &format!("Tamsayı taşması: {}", expr), 0, 0, None)
+}
@@ -1,151 +1,297 @@
-// src/ast.rs
-// NovaLang için kapsamlı ve genişletilebilir AST düğümleri
-
-#[derive(Debug, Clone)]
-pub struct Program {
-    pub body: Vec<Stmt>,
-}
-
-#[derive(Debug, Clone)]
-pub enum Stmt {
-    Let {
-        name: String,
-        value: Expr,
-        line: usize,
-        col: usize,
-    },
-    Expr(Expr),
-    Func {
-        name: String,
-        params: Vec<String>,
-        body: Vec<Stmt>,
-        line: usize,
-        col: usize,
-    },
-    If {
-        cond: Expr,
-        then_branch: Vec<Stmt>,
-        else_branch: Option<Vec<Stmt>>,
-        line: usize,
-        col: usize,
-    },
-    While {
-        cond: Expr,
-        body: Vec<Stmt>,
-        line: usize,
-        col: usize,
-    },
-    Return(Expr, usize, usize),
-    Block(Vec<Stmt>),
-    TryCatch {
-        try_block: Vec<Stmt>,
-        err_name: String,
-        catch_block: Vec<Stmt>,
-        line: usize,
-        col: usize,
-    },
-    TryCatchFinally {
-        try_block: Vec<Stmt>,
-        err_name: String,
-        catch_block: Vec<Stmt>,
-        finally_block: Vec<Stmt>,
-        line: usize,
-        col: usize,
-    },
-    Import {
-        name: String,
-        line: usize,
-        col: usize,
-    },
-    Lambda {
-        params: Vec<String>,
-        body: Box<Expr>,
-        line: usize,
-        col: usize,
-    },
-    Class {
-        name: String,
-        base: Option<String>,
-        methods: Vec<Stmt>,
-        line: usize,
-        col: usize,
-    },
-    Export {
-        name: String,
-        value: Expr,
-        line: usize,
-        col: usize,
-    },
-    AsyncFunc {
-        name: String,
-        params: Vec<String>,
-        body: Vec<Stmt>,
-        line: usize,
-        col: usize,
-    },
-}
-
-#[derive(Debug, Clone)]
-pub enum Expr {
-    Int(i64, usize, usize),
-    Float(f64, usize, usize),
-    String(String, usize, usize),
-    Bool(bool, usize, usize),
-    Null(usize, usize),
-    Identifier(String, usize, usize),
-    BinaryOp {
-        left: Box<Expr>,
-        op: String,
-        right: Box<Expr>,
-        line: usize,
-        col: usize,
-    },
-    UnaryOp {
-        op: String,
-        expr: Box<Expr>,
-        line: usize,
-        col: usize,
-    },
-    Call {
-        func: Box<Expr>,
-        args: Vec<Expr>,
-        line: usize,
-        col: usize,
-    },
-    Assign {
-        name: String,
-        value: Box<Expr>,
-        line: usize,
-        col: usize,
-    },
-    List(Vec<Expr>, usize, usize),
-    Map(Vec<(Expr, Expr)>, usize, usize),
-    Index {
-        collection: Box<Expr>,
-        index: Box<Expr>,
-        line: usize,
-        col: usize,
-    },
-    Object {
-        class_name: String,
-        fields: std::collections::HashMap<String, crate::vm::Value>,
-    },
-    MethodCall {
-        object: Box<Expr>,
-        method: String,
-        args: Vec<Expr>,
-        line: usize,
-        col: usize,
-    },
-    New {
-        class_name: String,
-        args: Vec<Expr>,
-        line: usize,
-        col: usize,
-    },
-    Await {
-        expr: Box<Expr>,
-        line: usize,
-        col: usize,
-    },
-} 
\ No newline at end of file
+// src/ast.rs
+// NovaLang için kapsamlı ve genişletilebilir AST düğümleri
+
+use crate::span::Span;
+
+/// Resolver'ın bulduğu yerel değişken konumu: kaç kapsam yukarıda (`depth`) ve o
+/// kapsamdaki kaçıncı tanım (`index`). Global ve çözülemeyen isimlerde `None` kalır.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slot {
+    pub depth: usize,
+    pub index: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Program {
+    pub body: Vec<Stmt>,
+}
+
+/// `catch (e) { }`; `catch (e: TypeError) { }` yalnızca o türdeki hataları yakalar.
+/// Bir try'ın catch'lerinden hatayla eşleşen ilki çalışır, hiçbiri eşleşmezse hata dışarı taşınır.
+#[derive(Debug, Clone)]
+pub struct CatchClause {
+    pub name: String,
+    pub kind: Option<String>,
+    pub body: Vec<Stmt>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub enum Stmt {
+    Let {
+        name: String,
+        value: Expr,
+        span: Span,
+    },
+    Expr(Expr),
+    Func {
+        name: String,
+        params: Vec<String>,
+        body: Vec<Stmt>,
+        doc: Option<String>,
+        span: Span,
+    },
+    If {
+        cond: Expr,
+        then_branch: Vec<Stmt>,
+        else_branch: Option<Vec<Stmt>>,
+        span: Span,
+    },
+    While {
+        cond: Expr,
+        body: Vec<Stmt>,
+        span: Span,
+    },
+    // for x in xs { } ya da for k, v in m { }
+    For {
+        name: String,
+        value_name: Option<String>,
+        iterable: Expr,
+        body: Vec<Stmt>,
+        span: Span,
+    },
+    Return(Expr, Span),
+    // throw ifade; — herhangi bir değer fırlatılabilir
+    Throw(Expr, Span),
+    Break(Span),
+    Continue(Span),
+    Block(Vec<Stmt>, Span),
+    TryCatch {
+        try_block: Vec<Stmt>,
+        catches: Vec<CatchClause>,
+        span: Span,
+    },
+    // `try { } finally { }` biçiminde catch listesi boştur
+    TryCatchFinally {
+        try_block: Vec<Stmt>,
+        catches: Vec<CatchClause>,
+        finally_block: Vec<Stmt>,
+        span: Span,
+    },
+    Import {
+        name: String,
+        span: Span,
+    },
+    Class {
+        name: String,
+        base: Option<String>,
+        // Üst sınıf sözcüksel olarak çözülür; resolver doldurur
+        base_slot: Option<Slot>,
+        // `let alan;` bildirimleri; başlangıç değeri verilmeyen alanlar null olur
+        fields: Vec<(String, Expr)>,
+        methods: Vec<Stmt>,
+        doc: Option<String>,
+        span: Span,
+    },
+    // export let/func/class: `decl` dışa aktarılan bildirimdir
+    Export {
+        name: String,
+        decl: Box<Stmt>,
+        doc: Option<String>,
+        span: Span,
+    },
+    AsyncFunc {
+        name: String,
+        params: Vec<String>,
+        body: Vec<Stmt>,
+        doc: Option<String>,
+        span: Span,
+    },
+}
+
+impl Stmt {
+    pub fn span(&self) -> Span {
+        match self {
+            Stmt::Expr(expr) => expr.span(),
+            Stmt::Return(_, span) | Stmt::Throw(_, span) | Stmt::Break(span) | Stmt::Continue(span) | Stmt::Block(_, span) => *span,
+            Stmt::Let { span, .. }
+            | Stmt::Func { span, .. }
+            | Stmt::If { span, .. }
+            | Stmt::While { span, .. }
+            | Stmt::For { span, .. }
+            | Stmt::TryCatch { span, .. }
+            | Stmt::TryCatchFinally { span, .. }
+            | Stmt::Import { span, .. }
+            | Stmt::Class { span, .. }
+            | Stmt::Export { span, .. }
+            | Stmt::AsyncFunc { span, .. } => *span,
+        }
+    }
+
+    /// `///` doc yorumunu belgelenebilen deyimlere (func, class, export) ekler.
+    pub fn with_doc(mut self, text: Option<String>) -> Stmt {
+        match &mut self {
+            Stmt::Func { doc, .. }
+            | Stmt::AsyncFunc { doc, .. }
+            | Stmt::Class { doc, .. }
+            | Stmt::Export { doc, .. } => *doc = text,
+            _ => {}
+        }
+        self
+    }
+}
+
+/// Programdaki belgelenmiş isimleri ve doc metinlerini toplar; sınıf metotları
+/// `Sınıf.metot` biçiminde adlandırılır.
+pub fn collect_docs(program: &Program) -> Vec<(String, String)> {
+    fn visit(stmt: &Stmt, prefix: &str, out: &mut Vec<(String, String)>) {
+        let (name, doc) = match stmt {
+            Stmt::Func { name, doc, .. }
+            | Stmt::AsyncFunc { name, doc, .. }
+            | Stmt::Export { name, doc, .. } => (name, doc),
+            Stmt::Class { name, doc, methods, .. } => {
+                for method in methods {
+                    visit(method, &format!("{}.", name), out);
+                }
+                (name, doc)
+            }
+            _ => return,
+        };
+        if let Some(text) = doc {
+            out.push((format!("{}{}", prefix, name), text.clone()));
+        }
+    }
+    let mut out = Vec::new();
+    for stmt in &program.body {
+        visit(stmt, "", &mut out);
+    }
+    out
+}
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Int(i64, Span),
+    Float(f64, Span),
+    String(String, Span),
+    Bool(bool, Span),
+    Null(Span),
+    Identifier(String, Option<Slot>, Span),
+    BinaryOp {
+        left: Box<Expr>,
+        op: String,
+        right: Box<Expr>,
+        span: Span,
+    },
+    UnaryOp {
+        op: String,
+        expr: Box<Expr>,
+        span: Span,
+    },
+    // start..end ya da start..=end
+    Range {
+        start: Box<Expr>,
+        end: Box<Expr>,
+        inclusive: bool,
+        span: Span,
+    },
+    Call {
+        func: Box<Expr>,
+        args: Vec<Expr>,
+        span: Span,
+    },
+    Assign {
+        name: String,
+        value: Box<Expr>,
+        slot: Option<Slot>,
+        span: Span,
+    },
+    List(Vec<Expr>, Span),
+    Map(Vec<(Expr, Expr)>, Span),
+    Index {
+        collection: Box<Expr>,
+        index: Box<Expr>,
+        span: Span,
+    },
+    // liste[i] = değer, map["anahtar"] = değer
+    SetIndex {
+        collection: Box<Expr>,
+        index: Box<Expr>,
+        value: Box<Expr>,
+        span: Span,
+    },
+    // Parser henüz üretmiyor; nesneler sınıf çağrısıyla kurulur
+    #[allow(dead_code)]
+    Object {
+        class_name: String,
+        fields: std::collections::HashMap<String, crate::vm::Value>,
+        span: Span,
+    },
+    // obj.alan
+    Get {
+        object: Box<Expr>,
+        name: String,
+        span: Span,
+    },
+    // obj.alan = değer
+    Set {
+        object: Box<Expr>,
+        name: String,
+        value: Box<Expr>,
+        span: Span,
+    },
+    MethodCall {
+        object: Box<Expr>,
+        method: String,
+        args: Vec<Expr>,
+        span: Span,
+    },
+    New {
+        class_name: String,
+        args: Vec<Expr>,
+        span: Span,
+    },
+    Await {
+        expr: Box<Expr>,
+        span: Span,
+    },
+    Lambda {
+        params: Vec<String>,
+        body: Box<Expr>,
+        span: Span,
+    },
+    // "Merhaba ${isim}": metin parçaları Expr::String, gömülü ifadeler olduğu gibi tutulur
+    Interpolation {
+        parts: Vec<Expr>,
+        span: Span,
+    },
+}
+
+impl Expr {
+    pub fn span(&self) -> Span {
+        match self {
+            Expr::Int(_, span)
+            | Expr::Float(_, span)
+            | Expr::String(_, span)
+            | Expr::Bool(_, span)
+            | Expr::Null(span)
+            | Expr::Identifier(_, _, span)
+            | Expr::List(_, span)
+            | Expr::Map(_, span) => *span,
+            Expr::BinaryOp { span, .. }
+            | Expr::UnaryOp { span, .. }
+            | Expr::Range { span, .. }
+            | Expr::Call { span, .. }
+            | Expr::Assign { span, .. }
+            | Expr::Index { span, .. }
+            | Expr::SetIndex { span, .. }
+            | Expr::Object { span, .. }
+            | Expr::Get { span, .. }
+            | Expr::Set { span, .. }
+            | Expr::MethodCall { span, .. }
+            | Expr::New { span, .. }
+            | Expr::Await { span, .. }
+            | Expr::Lambda { span, .. }
+            | Expr::Interpolation { span, .. } => *span,
+        }
+    }
+}
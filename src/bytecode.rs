@@ -0,0 +1,117 @@
+// src/bytecode.rs
+// NovaLang için bytecode komut kümesi ve derlenmiş modül yapısı
+
+use crate::span::Span;
+
+/// Yığın makinesi komutları. İşlenenler sabit havuzundaki sıra (`u16`), çağrı
+/// çerçevesindeki yerel yuva ya da koddaki mutlak hedef konumdur (`u32`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Constant(u16),
+    Null,
+    True,
+    False,
+    Pop,
+    Dup,
+    GetLocal(u16),
+    SetLocal(u16),
+    GetUpvalue(u16),
+    SetUpvalue(u16),
+    // Yığının tepesindeki yakalanmış yereli kapatıp atar
+    CloseUpvalue,
+    DefineGlobal(u16),
+    GetGlobal(u16),
+    SetGlobal(u16),
+    GetField(u16),
+    SetField(u16),
+    GetIndex,
+    SetIndex,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Equal,
+    NotEqual,
+    Less,
+    Greater,
+    LessEqual,
+    GreaterEqual,
+    Negate,
+    Not,
+    Jump(u32),
+    // Koşulu yığından alır; yanlışsa atlar
+    JumpIfFalse(u32),
+    // Yığındaki değerin yerine for-in yineleyicisini koyar
+    Iter,
+    // Tepedeki yineleyiciden sıradaki öğeyi (Pair: anahtar ve değeri) iter; bittiyse atlar
+    IterNext(u32),
+    IterNextPair(u32),
+    Range,
+    RangeInclusive,
+    Call(u8),
+    // Global isimli fonksiyon; tanımlı değilse aynı isimli yerleşik çağrılır
+    CallGlobal(u16, u8),
+    Invoke(u16, u8),
+    // super.metot(...): arama argümanların üstündeki üst sınıftan başlar
+    SuperInvoke(u16, u8),
+    Closure(u16),
+    Return,
+    // Yığının tepesindeki değeri hata olarak fırlatır
+    Throw,
+    BuildList(u16),
+    BuildMap(u16),
+    Interpolate(u16),
+    Class(u16),
+    // Yığının tepesindeki sınıfa altındaki değeri üst sınıf yapar; değer sınıf değilse hata
+    Inherit(u16),
+    Method(u16),
+    // Alan başlatıcısı: yığındaki parametresiz closure her nesne için çağrılır
+    Field(u16),
+    Import(u16),
+    // Hata olursa yığını o anki yüksekliğe indirip hedefe atlar
+    PushHandler(u32),
+    PopHandler,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constant {
+    Int(i64),
+    Float(f64),
+    String(String),
+    // Modülün fonksiyon tablosundaki sıra
+    Function(u16),
+}
+
+/// Closure oluşturulurken yakalanan değişken: çevreleyen fonksiyonun yereli
+/// (`is_local`) ya da çevreleyen fonksiyonun kendi upvalue'su.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UpvalueDesc {
+    pub is_local: bool,
+    pub index: u16,
+}
+
+/// Komutlar, sabit havuzu ve her komutun kaynak aralığı. `.novac` dosyasından
+/// yüklenen chunk'larda kaynak metni olmayabilir; konumlar `lines` tablosundan gelir.
+#[derive(Debug, Clone, Default)]
+pub struct Chunk {
+    pub code: Vec<Op>,
+    pub constants: Vec<Constant>,
+    pub spans: Vec<Span>,
+    pub lines: Vec<(u32, u32)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub arity: u8,
+    pub chunk: Chunk,
+    pub upvalues: Vec<UpvalueDesc>,
+}
+
+/// Derlenmiş bir kaynak dosyası; 0. fonksiyon dosyanın üst düzey kodudur.
+#[derive(Debug, Clone, Default)]
+pub struct Module {
+    pub functions: Vec<Function>,
+    pub exports: Vec<String>,
+}
@@ -0,0 +1,852 @@
+// src/compiler.rs
+// NovaLang için AST'den bytecode'a derleyici
+
+use crate::ast::{CatchClause, Expr, Program, Stmt};
+use crate::bytecode::{Chunk, Constant, Function, Module, Op, UpvalueDesc};
+use crate::error::{NovaError, NovaErrorKind};
+use crate::span::Span;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FunctionKind {
+    Script,
+    Function,
+    Method,
+}
+
+struct Local {
+    name: String,
+    depth: usize,
+    captured: bool,
+}
+
+/// Derlenmekte olan döngü: `continue` hedefi, döngü dışındaki kapsam derinliği,
+/// döngüye girerken açık olan try sayısı ve sonradan yamanacak `break` atlamaları.
+struct Loop {
+    start: u32,
+    scope_depth: usize,
+    handlers: usize,
+    breaks: Vec<usize>,
+}
+
+/// Açık bir try'ın finally bloğu ve try'dan önce açık olan try sayısı. Bloktan
+/// `return`, `break` ya da `continue` ile çıkılırken blok satır içine derlenir.
+#[derive(Clone)]
+struct Finally {
+    handlers: usize,
+    body: Vec<Stmt>,
+}
+
+struct FunctionState {
+    index: usize,
+    function: Function,
+    kind: FunctionKind,
+    locals: Vec<Local>,
+    scope_depth: usize,
+    loops: Vec<Loop>,
+    // Açık try bloklarının sayısı
+    handlers: usize,
+    // Açık finally blokları; en içteki sonda
+    finally: Vec<Finally>,
+    // Alan başlatıcıları global ortamda çalışır; çevreleyen yerelleri yakalayamaz
+    isolated: bool,
+}
+
+/// Programı derler. Yereller çağrı çerçevesindeki yuvalarda, blok dışına taşan
+/// yereller upvalue olarak tutulur; üst düzey isimler globaldir.
+pub struct Compiler {
+    module: Module,
+    states: Vec<FunctionState>,
+}
+
+impl Compiler {
+    pub fn new() -> Self {
+        Compiler { module: Module::default(), states: Vec::new() }
+    }
+
+    pub fn compile(mut self, program: &Program) -> Result<Module, NovaError> {
+        self.begin_function("<script>", FunctionKind::Script, false);
+        for stmt in &program.body {
+            self.stmt(stmt)?;
+        }
+        let span = program.body.last().map(Stmt::span).unwrap_or_default();
+        self.end_function(span);
+        Ok(self.module)
+    }
+
+    // --- Fonksiyon durumu ---
+
+    fn state(&mut self) -> &mut FunctionState {
+        self.states.last_mut().expect("derlenen fonksiyon yok")
+    }
+
+    fn chunk(&mut self) -> &mut Chunk {
+        &mut self.state().function.chunk
+    }
+
+    fn begin_function(&mut self, name: &str, kind: FunctionKind, isolated: bool) {
+        let index = self.module.functions.len();
+        let function = Function { name: name.to_string(), arity: 0, chunk: Chunk::default(), upvalues: Vec::new() };
+        // Yer tutucu; iç fonksiyonlar bundan sonraki sıraları alır
+        self.module.functions.push(function.clone());
+        // 0. yuva metotlarda `this`, diğer fonksiyonlarda çağrılan değerin kendisidir
+        let slot0 = if kind == FunctionKind::Method { "this" } else { "" };
+        let locals = vec![Local { name: slot0.to_string(), depth: 0, captured: false }];
+        let state = FunctionState { index, function, kind, locals, scope_depth: 0, loops: Vec::new(), handlers: 0, finally: Vec::new(), isolated };
+        self.states.push(state);
+    }
+
+    /// Örtük `return null` ekler ve fonksiyonu modül tablosundaki yerine koyar.
+    fn end_function(&mut self, span: Span) -> usize {
+        self.emit(Op::Null, span);
+        self.emit(Op::Return, span);
+        let state = self.states.pop().expect("derlenen fonksiyon yok");
+        self.module.functions[state.index] = state.function;
+        state.index
+    }
+
+    /// Parametreli bir fonksiyon gövdesi derler ve onu oluşturan `Closure` komutunu yazar.
+    fn function(
+        &mut self,
+        name: &str,
+        kind: FunctionKind,
+        params: &[String],
+        span: Span,
+        body: impl FnOnce(&mut Self) -> Result<(), NovaError>,
+    ) -> Result<(), NovaError> {
+        if params.len() > u8::MAX as usize {
+            return Err(compile_error("Bir fonksiyon en fazla 255 parametre alabilir", span));
+        }
+        self.begin_function(name, kind, false);
+        self.state().function.arity = params.len() as u8;
+        self.begin_scope();
+        for param in params {
+            self.add_local(param, span)?;
+        }
+        body(self)?;
+        self.finish_closure(span)
+    }
+
+    fn finish_closure(&mut self, span: Span) -> Result<(), NovaError> {
+        let index = self.end_function(span);
+        let constant = self.make_constant(Constant::Function(index as u16), span)?;
+        self.emit(Op::Closure(constant), span);
+        Ok(())
+    }
+
+    // --- Yazma yardımcıları ---
+
+    fn emit(&mut self, op: Op, span: Span) -> usize {
+        let chunk = self.chunk();
+        chunk.code.push(op);
+        chunk.spans.push(span);
+        chunk.code.len() - 1
+    }
+
+    fn make_constant(&mut self, constant: Constant, span: Span) -> Result<u16, NovaError> {
+        let constants = &mut self.chunk().constants;
+        if let Some(i) = constants.iter().position(|c| *c == constant) {
+            return Ok(i as u16);
+        }
+        if constants.len() > u16::MAX as usize {
+            return Err(compile_error("Bir fonksiyonda en fazla 65536 sabit olabilir", span));
+        }
+        constants.push(constant);
+        Ok((constants.len() - 1) as u16)
+    }
+
+    fn name_constant(&mut self, name: &str, span: Span) -> Result<u16, NovaError> {
+        self.make_constant(Constant::String(name.to_string()), span)
+    }
+
+    fn here(&mut self) -> u32 {
+        self.chunk().code.len() as u32
+    }
+
+    /// Daha önce yazılmış atlama komutunun hedefini şu anki konuma çevirir.
+    fn patch_jump(&mut self, at: usize) {
+        let target = self.here();
+        match &mut self.chunk().code[at] {
+            Op::Jump(t) | Op::JumpIfFalse(t) | Op::IterNext(t) | Op::IterNextPair(t) | Op::PushHandler(t) => *t = target,
+            op => unreachable!("atlama olmayan komut yamalanamaz: {:?}", op),
+        }
+    }
+
+    // --- Kapsamlar ve değişkenler ---
+
+    fn begin_scope(&mut self) {
+        self.state().scope_depth += 1;
+    }
+
+    fn end_scope(&mut self, span: Span) {
+        let state = self.state();
+        state.scope_depth -= 1;
+        let depth = state.scope_depth;
+        while let Some(local) = self.state().locals.pop_if(|local| local.depth > depth) {
+            let op = if local.captured { Op::CloseUpvalue } else { Op::Pop };
+            self.emit(op, span);
+        }
+    }
+
+    /// Kapsamı kod üretmeden kapatır; kapsamın sonuna gelinmez çünkü son komut
+    /// (`Return` ya da `Throw`) çerçeveden veya try'dan zaten çıkar.
+    fn discard_scope(&mut self) {
+        let state = self.state();
+        state.scope_depth -= 1;
+        let depth = state.scope_depth;
+        state.locals.retain(|local| local.depth <= depth);
+    }
+
+    /// Son eklenen yerelin yuvası.
+    fn last_local(&mut self) -> u16 {
+        (self.state().locals.len() - 1) as u16
+    }
+
+    /// Üst düzey kodda kapsam dışındaki bildirimler globaldir.
+    fn at_global_scope(&mut self) -> bool {
+        let state = self.state();
+        state.kind == FunctionKind::Script && state.scope_depth == 0
+    }
+
+    fn add_local(&mut self, name: &str, span: Span) -> Result<(), NovaError> {
+        let state = self.state();
+        if state.locals.len() > u16::MAX as usize {
+            return Err(compile_error("Bir fonksiyonda çok fazla yerel değişken var", span));
+        }
+        let depth = state.scope_depth;
+        state.locals.push(Local { name: name.to_string(), depth, captured: false });
+        Ok(())
+    }
+
+    fn resolve_local(&self, state: usize, name: &str) -> Option<u16> {
+        self.states[state].locals.iter().rposition(|local| local.name == name).map(|i| i as u16)
+    }
+
+    fn resolve_upvalue(&mut self, state: usize, name: &str) -> Option<u16> {
+        if state == 0 || self.states[state].isolated {
+            return None;
+        }
+        if let Some(local) = self.resolve_local(state - 1, name) {
+            self.states[state - 1].locals[local as usize].captured = true;
+            return Some(self.add_upvalue(state, true, local));
+        }
+        let upvalue = self.resolve_upvalue(state - 1, name)?;
+        Some(self.add_upvalue(state, false, upvalue))
+    }
+
+    fn add_upvalue(&mut self, state: usize, is_local: bool, index: u16) -> u16 {
+        let upvalues = &mut self.states[state].function.upvalues;
+        let desc = UpvalueDesc { is_local, index };
+        if let Some(i) = upvalues.iter().position(|u| *u == desc) {
+            return i as u16;
+        }
+        upvalues.push(desc);
+        (upvalues.len() - 1) as u16
+    }
+
+    /// İsmin okuma ve yazma komutları: önce yerel, sonra upvalue, en son global.
+    fn variable(&mut self, name: &str, span: Span) -> Result<(Op, Op), NovaError> {
+        let current = self.states.len() - 1;
+        // `self`, `this` için ikinci bir addır
+        let name = if name == "self" && self.lookup_exists(current, "self").is_none() { "this" } else { name };
+        if let Some(slot) = self.resolve_local(current, name) {
+            return Ok((Op::GetLocal(slot), Op::SetLocal(slot)));
+        }
+        if let Some(index) = self.resolve_upvalue(current, name) {
+            return Ok((Op::GetUpvalue(index), Op::SetUpvalue(index)));
+        }
+        let constant = self.name_constant(name, span)?;
+        Ok((Op::GetGlobal(constant), Op::SetGlobal(constant)))
+    }
+
+    /// İsmin yerel ya da yakalanabilir olarak bulunup bulunmadığı (yan etkisiz).
+    fn lookup_exists(&self, state: usize, name: &str) -> Option<()> {
+        let mut current = state;
+        loop {
+            if self.resolve_local(current, name).is_some() {
+                return Some(());
+            }
+            if current == 0 || self.states[current].isolated {
+                return None;
+            }
+            current -= 1;
+        }
+    }
+
+    /// `let`, `func` ve `class` bildirimlerini bitirir: globalse yığındaki değeri
+    /// global tabloya yazar, yerelse değer olduğu yerde yerel yuva olur.
+    fn define(&mut self, name: &str, global: bool, span: Span) -> Result<(), NovaError> {
+        if global {
+            let constant = self.name_constant(name, span)?;
+            self.emit(Op::DefineGlobal(constant), span);
+        }
+        Ok(())
+    }
+
+    // --- Deyimler ---
+
+    fn stmt(&mut self, stmt: &Stmt) -> Result<(), NovaError> {
+        match stmt {
+            Stmt::Let { name, value, span } => {
+                let global = self.at_global_scope();
+                self.expr(value)?;
+                if !global {
+                    self.add_local(name, *span)?;
+                }
+                self.define(name, global, *span)
+            }
+            Stmt::Expr(expr) => {
+                self.expr(expr)?;
+                self.emit(Op::Pop, expr.span());
+                Ok(())
+            }
+            Stmt::Func { name, params, body, span, .. } | Stmt::AsyncFunc { name, params, body, span, .. } => {
+                let global = self.at_global_scope();
+                self.function(name, FunctionKind::Function, params, *span, |c| c.stmts(body))?;
+                if global {
+                    return self.define(name, global, *span);
+                }
+                // Yuva blok başında açıldı (bkz. `stmts`); closure oraya yazılır
+                let current = self.states.len() - 1;
+                let slot = self.resolve_local(current, name).expect("yerel fonksiyon önceden bildirilmeli");
+                self.emit(Op::SetLocal(slot), *span);
+                self.emit(Op::Pop, *span);
+                Ok(())
+            }
+            Stmt::If { cond, then_branch, else_branch, span } => {
+                self.expr(cond)?;
+                let else_jump = self.emit(Op::JumpIfFalse(0), *span);
+                self.block(then_branch, *span)?;
+                let end_jump = self.emit(Op::Jump(0), *span);
+                self.patch_jump(else_jump);
+                if let Some(else_branch) = else_branch {
+                    self.block(else_branch, *span)?;
+                }
+                self.patch_jump(end_jump);
+                Ok(())
+            }
+            Stmt::While { cond, body, span } => {
+                let start = self.here();
+                self.expr(cond)?;
+                let exit_jump = self.emit(Op::JumpIfFalse(0), *span);
+                self.begin_loop(start);
+                self.block(body, *span)?;
+                self.emit(Op::Jump(start), *span);
+                self.patch_jump(exit_jump);
+                self.end_loop();
+                Ok(())
+            }
+            // Yineleyici döngü boyunca gizli bir yerelde durur; tur değişkenleri ve
+            // gövde her turda açılıp kapanan kapsamdadır
+            Stmt::For { name, value_name, iterable, body, span } => {
+                self.begin_scope();
+                self.expr(iterable)?;
+                self.emit(Op::Iter, *span);
+                self.add_local("", *span)?;
+                let start = self.here();
+                let next = if value_name.is_some() { Op::IterNextPair(0) } else { Op::IterNext(0) };
+                let exit_jump = self.emit(next, *span);
+                self.begin_loop(start);
+                self.begin_scope();
+                self.add_local(name, *span)?;
+                if let Some(value_name) = value_name {
+                    self.add_local(value_name, *span)?;
+                }
+                self.stmts(body)?;
+                self.end_scope(*span);
+                self.emit(Op::Jump(start), *span);
+                self.patch_jump(exit_jump);
+                self.end_loop();
+                self.end_scope(*span);
+                Ok(())
+            }
+            Stmt::Break(span) => {
+                self.exit_loop(*span)?;
+                let jump = self.emit(Op::Jump(0), *span);
+                self.state().loops.last_mut().expect("döngü dışında break").breaks.push(jump);
+                Ok(())
+            }
+            Stmt::Continue(span) => {
+                let start = self.exit_loop(*span)?;
+                self.emit(Op::Jump(start), *span);
+                Ok(())
+            }
+            Stmt::Return(expr, span) => {
+                if self.state().kind == FunctionKind::Script {
+                    return Err(compile_error("return yalnızca fonksiyon içinde kullanılabilir", *span));
+                }
+                self.expr(expr)?;
+                if self.state().finally.is_empty() {
+                    self.emit(Op::Return, *span);
+                    return Ok(());
+                }
+                // Dönüş değeri gizli bir yerelde beklerken açık finally blokları çalışır
+                self.begin_scope();
+                self.add_local("", *span)?;
+                let slot = self.last_local();
+                self.unwind(0, *span)?;
+                self.emit(Op::GetLocal(slot), *span);
+                self.emit(Op::Return, *span);
+                self.discard_scope();
+                Ok(())
+            }
+            Stmt::Throw(expr, span) => {
+                self.expr(expr)?;
+                self.emit(Op::Throw, *span);
+                Ok(())
+            }
+            Stmt::Block(body, span) => self.block(body, *span),
+            Stmt::TryCatch { try_block, catches, span } => self.try_catch(try_block, catches, *span),
+            Stmt::TryCatchFinally { try_block, catches, finally_block, span } => {
+                self.try_finally(try_block, catches, finally_block, *span)
+            }
+            Stmt::Import { name, span } => {
+                let constant = self.name_constant(name, *span)?;
+                self.emit(Op::Import(constant), *span);
+                Ok(())
+            }
+            Stmt::Class { name, base, fields, methods, span, .. } => self.class(name, base, fields, methods, *span),
+            Stmt::Export { name, decl, .. } => {
+                self.stmt(decl)?;
+                if self.at_global_scope() {
+                    self.module.exports.push(name.clone());
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Yerel kapsamdaki fonksiyonların yuvaları blok başında açılır; böylece aynı
+    /// bloktaki fonksiyonlar birbirini ve kendilerini çağırabilir.
+    fn stmts(&mut self, stmts: &[Stmt]) -> Result<(), NovaError> {
+        if !self.at_global_scope() {
+            for stmt in stmts {
+                if let Stmt::Func { name, span, .. } | Stmt::AsyncFunc { name, span, .. } = stmt {
+                    self.emit(Op::Null, *span);
+                    self.add_local(name, *span)?;
+                }
+            }
+        }
+        for stmt in stmts {
+            self.stmt(stmt)?;
+        }
+        Ok(())
+    }
+
+    fn block(&mut self, body: &[Stmt], span: Span) -> Result<(), NovaError> {
+        self.begin_scope();
+        self.stmts(body)?;
+        self.end_scope(span);
+        Ok(())
+    }
+
+    fn begin_loop(&mut self, start: u32) {
+        let state = self.state();
+        let current = Loop { start, scope_depth: state.scope_depth, handlers: state.handlers, breaks: Vec::new() };
+        state.loops.push(current);
+    }
+
+    /// `break` atlamalarını döngünün bittiği yere yönlendirir.
+    fn end_loop(&mut self) {
+        let current = self.state().loops.pop().expect("kapatılacak döngü yok");
+        for jump in current.breaks {
+            self.patch_jump(jump);
+        }
+    }
+
+    /// Döngüden çıkmadan önce döngü içinde açılan try'ları (finally bloklarını
+    /// çalıştırarak) kapatır ve yerelleri atar; kapsamlar derleyicide açık kalır.
+    /// Döngünün başını döndürür.
+    fn exit_loop(&mut self, span: Span) -> Result<u32, NovaError> {
+        let current = match self.state().loops.last() {
+            Some(current) => current,
+            None => return Err(compile_error("break ve continue yalnızca döngü içinde kullanılabilir", span)),
+        };
+        let (start, depth, handlers) = (current.start, current.scope_depth, current.handlers);
+        self.unwind(handlers, span)?;
+        let state = self.state();
+        let captured: Vec<bool> = state.locals.iter().rev().take_while(|local| local.depth > depth).map(|local| local.captured).collect();
+        for captured in captured {
+            self.emit(if captured { Op::CloseUpvalue } else { Op::Pop }, span);
+        }
+        Ok(start)
+    }
+
+    /// `floor` sayısının üstündeki try'ları kapatır; aradaki finally blokları içten
+    /// dışa satır içine derlenir. Blok derlenirken kendisi ve içindeki try'lar açık
+    /// sayılmaz, böylece bloktaki hata dıştaki try'a gider. Derleyici durumu sonra geri gelir.
+    fn unwind(&mut self, floor: usize, span: Span) -> Result<(), NovaError> {
+        let (handlers, finally) = (self.state().handlers, self.state().finally.clone());
+        while let Some(pending) = self.state().finally.pop_if(|f| f.handlers >= floor) {
+            for _ in pending.handlers..self.state().handlers {
+                self.emit(Op::PopHandler, span);
+            }
+            self.state().handlers = pending.handlers;
+            self.block(&pending.body, span)?;
+        }
+        for _ in floor..self.state().handlers {
+            self.emit(Op::PopHandler, span);
+        }
+        let state = self.state();
+        state.handlers = handlers;
+        state.finally = finally;
+        Ok(())
+    }
+
+    /// Hata olursa VM yığını try başındaki yüksekliğe indirir ve hata nesnesini
+    /// iter; bu değer gizli bir yerelde durur. catch'ler sırayla denenir: türü uyan
+    /// ilki hata değişkenine bu değerin kopyasıyla girer. Hiçbiri uymazsa aynı hata
+    /// nesnesi yeniden fırlatılır. catch listesi boşsa yalnızca try bloğu derlenir.
+    fn try_catch(&mut self, try_block: &[Stmt], catches: &[CatchClause], span: Span) -> Result<(), NovaError> {
+        if catches.is_empty() {
+            return self.block(try_block, span);
+        }
+        let handler = self.emit(Op::PushHandler(0), span);
+        self.state().handlers += 1;
+        self.block(try_block, span)?;
+        self.state().handlers -= 1;
+        self.emit(Op::PopHandler, span);
+        let end_jump = self.emit(Op::Jump(0), span);
+        self.patch_jump(handler);
+        self.begin_scope();
+        self.add_local("", span)?;
+        let slot = self.last_local();
+        let mut caught_jumps = Vec::new();
+        for catch in catches {
+            let mismatch = match &catch.kind {
+                Some(kind) => {
+                    self.emit(Op::GetLocal(slot), catch.span);
+                    let field = self.name_constant("kind", catch.span)?;
+                    self.emit(Op::GetField(field), catch.span);
+                    let kind = self.name_constant(kind, catch.span)?;
+                    self.emit(Op::Constant(kind), catch.span);
+                    self.emit(Op::Equal, catch.span);
+                    Some(self.emit(Op::JumpIfFalse(0), catch.span))
+                }
+                None => None,
+            };
+            self.begin_scope();
+            self.emit(Op::GetLocal(slot), catch.span);
+            self.add_local(&catch.name, catch.span)?;
+            self.stmts(&catch.body)?;
+            self.end_scope(span);
+            caught_jumps.push(self.emit(Op::Jump(0), span));
+            match mismatch {
+                Some(mismatch) => self.patch_jump(mismatch),
+                // Türsüz catch her hatayı yakalar; sonrasındakilere sıra gelmez
+                None => break,
+            }
+        }
+        if catches.iter().all(|catch| catch.kind.is_some()) {
+            self.emit(Op::GetLocal(slot), span);
+            self.emit(Op::Throw, span);
+        }
+        for jump in caught_jumps {
+            self.patch_jump(jump);
+        }
+        self.end_scope(span);
+        self.patch_jump(end_jump);
+        Ok(())
+    }
+
+    /// finally her çıkış yolunda bir kez çalışır: olağan bitişte try'dan (ya da
+    /// catch'ten) sonra; yakalanmayan hatada hata yeniden fırlatılmadan önce;
+    /// `return`/`break`/`continue`'da ise `unwind` bloğu çıkıştan önce derler.
+    fn try_finally(&mut self, try_block: &[Stmt], catches: &[CatchClause], finally_block: &[Stmt], span: Span) -> Result<(), NovaError> {
+        let handlers = self.state().handlers;
+        let handler = self.emit(Op::PushHandler(0), span);
+        self.state().handlers += 1;
+        self.state().finally.push(Finally { handlers, body: finally_block.to_vec() });
+        self.try_catch(try_block, catches, span)?;
+        self.state().finally.pop();
+        self.state().handlers -= 1;
+        self.emit(Op::PopHandler, span);
+        self.block(finally_block, span)?;
+        let end_jump = self.emit(Op::Jump(0), span);
+        // Hata yolu: hata nesnesi gizli bir yerelde bekler, finally'den sonra yeniden fırlatılır
+        self.patch_jump(handler);
+        self.begin_scope();
+        self.add_local("", span)?;
+        let slot = self.last_local();
+        self.block(finally_block, span)?;
+        self.emit(Op::GetLocal(slot), span);
+        self.emit(Op::Throw, span);
+        self.discard_scope();
+        self.patch_jump(end_jump);
+        Ok(())
+    }
+
+    fn class(
+        &mut self,
+        name: &str,
+        base: &Option<String>,
+        fields: &[(String, Expr)],
+        methods: &[Stmt],
+        span: Span,
+    ) -> Result<(), NovaError> {
+        let global = self.at_global_scope();
+        let name_constant = self.name_constant(name, span)?;
+        // Üst sınıflı yerel sınıfın yuvası `super` yerelinden önce ayrılır; sınıf bitince oraya yazılır
+        let slot = match base {
+            Some(_) if !global => {
+                self.emit(Op::Null, span);
+                self.add_local(name, span)?;
+                Some(self.last_local())
+            }
+            _ => None,
+        };
+        // Üst sınıf, metotların yakaladığı `super` yereline yüklenir
+        if let Some(base) = base {
+            self.begin_scope();
+            let (get, _) = self.variable(base, span)?;
+            self.emit(get, span);
+            self.add_local("super", span)?;
+        }
+        self.emit(Op::Class(name_constant), span);
+        if !global && base.is_none() {
+            self.add_local(name, span)?;
+        }
+        if let Some(base) = base {
+            let base_constant = self.name_constant(base, span)?;
+            self.emit(Op::Inherit(base_constant), span);
+        }
+        for (field, init) in fields {
+            let field_span = init.span();
+            self.begin_function(&format!("<alan {}>", field), FunctionKind::Function, true);
+            self.expr(init)?;
+            self.emit(Op::Return, field_span);
+            self.finish_closure(field_span)?;
+            let field_constant = self.name_constant(field, field_span)?;
+            self.emit(Op::Field(field_constant), field_span);
+        }
+        for method in methods {
+            if let Stmt::Func { name, params, body, span, .. } | Stmt::AsyncFunc { name, params, body, span, .. } = method {
+                self.function(name, FunctionKind::Method, params, *span, |c| c.stmts(body))?;
+                let method_constant = self.name_constant(name, *span)?;
+                self.emit(Op::Method(method_constant), *span);
+            }
+        }
+        match slot {
+            Some(slot) => {
+                self.emit(Op::SetLocal(slot), span);
+                self.emit(Op::Pop, span);
+            }
+            None => self.define(name, global, span)?,
+        }
+        if base.is_some() {
+            self.end_scope(span);
+        }
+        Ok(())
+    }
+
+    // --- İfadeler ---
+
+    fn expr(&mut self, expr: &Expr) -> Result<(), NovaError> {
+        match expr {
+            Expr::Int(i, span) => self.constant(Constant::Int(*i), *span),
+            Expr::Float(f, span) => self.constant(Constant::Float(*f), *span),
+            Expr::String(s, span) => self.constant(Constant::String(s.clone()), *span),
+            Expr::Bool(b, span) => {
+                self.emit(if *b { Op::True } else { Op::False }, *span);
+                Ok(())
+            }
+            Expr::Null(span) | Expr::Object { span, .. } => {
+                self.emit(Op::Null, *span);
+                Ok(())
+            }
+            Expr::Identifier(name, _, span) => {
+                let (get, _) = self.variable(name, *span)?;
+                self.emit(get, *span);
+                Ok(())
+            }
+            Expr::Assign { name, value, span, .. } => {
+                self.expr(value)?;
+                let (_, set) = self.variable(name, *span)?;
+                self.emit(set, *span);
+                Ok(())
+            }
+            Expr::BinaryOp { left, op, right, span } => self.binary(left, op, right, *span),
+            Expr::Range { start, end, inclusive, span } => {
+                self.expr(start)?;
+                self.expr(end)?;
+                self.emit(if *inclusive { Op::RangeInclusive } else { Op::Range }, *span);
+                Ok(())
+            }
+            Expr::UnaryOp { op, expr, span } => {
+                self.expr(expr)?;
+                let op = if op == "-" { Op::Negate } else { Op::Not };
+                self.emit(op, *span);
+                Ok(())
+            }
+            Expr::Call { func, args, span } => {
+                // Yerel olmayan isimler CallGlobal ile çağrılır; global yoksa yerleşik denenir
+                if let Expr::Identifier(name, _, _) = func.as_ref() {
+                    if let (Op::GetGlobal(constant), _) = self.variable(name, *span)? {
+                        let argc = self.args(args, *span)?;
+                        self.emit(Op::CallGlobal(constant, argc), *span);
+                        return Ok(());
+                    }
+                }
+                self.expr(func)?;
+                let argc = self.args(args, *span)?;
+                self.emit(Op::Call(argc), *span);
+                Ok(())
+            }
+            Expr::List(items, span) => {
+                for item in items {
+                    self.expr(item)?;
+                }
+                let count = self.count(items.len(), *span)?;
+                self.emit(Op::BuildList(count), *span);
+                Ok(())
+            }
+            Expr::Map(pairs, span) => {
+                for (k, v) in pairs {
+                    self.expr(k)?;
+                    self.expr(v)?;
+                }
+                let count = self.count(pairs.len(), *span)?;
+                self.emit(Op::BuildMap(count), *span);
+                Ok(())
+            }
+            Expr::Index { collection, index, span } => {
+                self.expr(collection)?;
+                self.expr(index)?;
+                self.emit(Op::GetIndex, *span);
+                Ok(())
+            }
+            Expr::SetIndex { collection, index, value, span } => {
+                self.expr(collection)?;
+                self.expr(index)?;
+                self.expr(value)?;
+                self.emit(Op::SetIndex, *span);
+                Ok(())
+            }
+            Expr::Get { object, name, span } => {
+                self.expr(object)?;
+                let constant = self.name_constant(name, *span)?;
+                self.emit(Op::GetField(constant), *span);
+                Ok(())
+            }
+            Expr::Set { object, name, value, span } => {
+                self.expr(object)?;
+                self.expr(value)?;
+                let constant = self.name_constant(name, *span)?;
+                self.emit(Op::SetField(constant), *span);
+                Ok(())
+            }
+            Expr::MethodCall { object, method, args, span } => {
+                let method_constant = self.name_constant(method, *span)?;
+                if let Expr::Identifier(name, _, _) = object.as_ref() {
+                    if name == "super" {
+                        let current = self.states.len() - 1;
+                        if self.lookup_exists(current, "super").is_none() {
+                            return Err(compile_error("super yalnızca alt sınıf metotlarında kullanılabilir", *span));
+                        }
+                        let (this, _) = self.variable("this", *span)?;
+                        self.emit(this, *span);
+                        let argc = self.args(args, *span)?;
+                        let (base, _) = self.variable("super", *span)?;
+                        self.emit(base, *span);
+                        self.emit(Op::SuperInvoke(method_constant, argc), *span);
+                        return Ok(());
+                    }
+                }
+                self.expr(object)?;
+                let argc = self.args(args, *span)?;
+                self.emit(Op::Invoke(method_constant, argc), *span);
+                Ok(())
+            }
+            Expr::New { class_name, args, span } => {
+                let (get, _) = self.variable(class_name, *span)?;
+                self.emit(get, *span);
+                let argc = self.args(args, *span)?;
+                self.emit(Op::Call(argc), *span);
+                Ok(())
+            }
+            // Async fonksiyonlar eşzamanlı çalıştığından await yalnızca değeri döndürür
+            Expr::Await { expr, .. } => self.expr(expr),
+            Expr::Lambda { params, body, span } => {
+                self.function("<lambda>", FunctionKind::Function, params, *span, |c| {
+                    c.expr(body)?;
+                    c.emit(Op::Return, body.span());
+                    Ok(())
+                })
+            }
+            Expr::Interpolation { parts, span } => {
+                for part in parts {
+                    self.expr(part)?;
+                }
+                let count = self.count(parts.len(), *span)?;
+                self.emit(Op::Interpolate(count), *span);
+                Ok(())
+            }
+        }
+    }
+
+    fn constant(&mut self, constant: Constant, span: Span) -> Result<(), NovaError> {
+        let index = self.make_constant(constant, span)?;
+        self.emit(Op::Constant(index), span);
+        Ok(())
+    }
+
+    fn binary(&mut self, left: &Expr, op: &str, right: &Expr, span: Span) -> Result<(), NovaError> {
+        self.expr(left)?;
+        // && ve || kısa devre yapar; sonuç son değerlendirilen işlenendir
+        match op {
+            "&&" => {
+                self.emit(Op::Dup, span);
+                let end_jump = self.emit(Op::JumpIfFalse(0), span);
+                self.emit(Op::Pop, span);
+                self.expr(right)?;
+                self.patch_jump(end_jump);
+                return Ok(());
+            }
+            "||" => {
+                self.emit(Op::Dup, span);
+                let else_jump = self.emit(Op::JumpIfFalse(0), span);
+                let end_jump = self.emit(Op::Jump(0), span);
+                self.patch_jump(else_jump);
+                self.emit(Op::Pop, span);
+                self.expr(right)?;
+                self.patch_jump(end_jump);
+                return Ok(());
+            }
+            _ => {}
+        }
+        self.expr(right)?;
+        let op = match op {
+            "+" => Op::Add,
+            "-" => Op::Subtract,
+            "*" => Op::Multiply,
+            "/" => Op::Divide,
+            "%" => Op::Modulo,
+            "==" => Op::Equal,
+            "!=" => Op::NotEqual,
+            "<" => Op::Less,
+            ">" => Op::Greater,
+            "<=" => Op::LessEqual,
+            ">=" => Op::GreaterEqual,
+            _ => return Err(compile_error(&format!("Bilinmeyen operatör: {}", op), span)),
+        };
+        self.emit(op, span);
+        Ok(())
+    }
+
+    fn args(&mut self, args: &[Expr], span: Span) -> Result<u8, NovaError> {
+        if args.len() > u8::MAX as usize {
+            return Err(compile_error("Bir çağrıda en fazla 255 argüman olabilir", span));
+        }
+        for arg in args {
+            self.expr(arg)?;
+        }
+        Ok(args.len() as u8)
+    }
+
+    fn count(&mut self, n: usize, span: Span) -> Result<u16, NovaError> {
+        u16::try_from(n).map_err(|_| compile_error("Çok fazla eleman", span))
+    }
+}
+
+fn compile_error(message: &str, span: Span) -> NovaError {
+    NovaError::at(NovaErrorKind::SemanticError, message, span)
+}
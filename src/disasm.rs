@@ -0,0 +1,170 @@
+// src/disasm.rs
+// NovaLang için okunabilir bytecode dökümü
+
+use crate::bytecode::{Chunk, Constant, Function, Module, Op};
+use crate::span::SourceMap;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Modüldeki her fonksiyonun sabit havuzunu, upvalue'larını ve komutlarını yazıya döker.
+/// Komutların önüne kaynak satırı, atlama hedeflerinin önüne `>` konur.
+pub fn disassemble(module: &Module, sources: &SourceMap) -> String {
+    let mut out = String::new();
+    for (index, function) in module.functions.iter().enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+        function_header(&mut out, index, function);
+        constants(&mut out, module, &function.chunk);
+        code(&mut out, module, function, sources);
+    }
+    if !module.exports.is_empty() {
+        let _ = writeln!(out, "\ndışa aktarılanlar: {}", module.exports.join(", "));
+    }
+    out
+}
+
+fn function_header(out: &mut String, index: usize, function: &Function) {
+    let _ = writeln!(out, "== #{} {} (parametre: {}) ==", index, function.name, function.arity);
+    for (i, upvalue) in function.upvalues.iter().enumerate() {
+        let source = if upvalue.is_local { "yerel" } else { "upvalue" };
+        let _ = writeln!(out, "  upvalue {} <- {} {}", i, source, upvalue.index);
+    }
+}
+
+fn constants(out: &mut String, module: &Module, chunk: &Chunk) {
+    if chunk.constants.is_empty() {
+        return;
+    }
+    out.push_str("sabitler:\n");
+    for (i, constant) in chunk.constants.iter().enumerate() {
+        let _ = writeln!(out, "  [{:>3}] {}", i, constant_text(module, constant));
+    }
+}
+
+fn constant_text(module: &Module, constant: &Constant) -> String {
+    match constant {
+        Constant::Int(i) => i.to_string(),
+        Constant::Float(f) => format!("{:?}", f),
+        Constant::String(s) => format!("{:?}", s),
+        Constant::Function(index) => {
+            let name = module.functions.get(*index as usize).map_or("?", |f| f.name.as_str());
+            format!("<fn {} #{}>", name, index)
+        }
+    }
+}
+
+fn code(out: &mut String, module: &Module, function: &Function, sources: &SourceMap) {
+    let chunk = &function.chunk;
+    let targets: HashSet<usize> = chunk
+        .code
+        .iter()
+        .filter_map(|op| match op {
+            Op::Jump(target)
+            | Op::JumpIfFalse(target)
+            | Op::IterNext(target)
+            | Op::IterNextPair(target)
+            | Op::PushHandler(target) => Some(*target as usize),
+            _ => None,
+        })
+        .collect();
+    out.push_str("kod:\n");
+    let mut last_line = None;
+    for (offset, op) in chunk.code.iter().enumerate() {
+        let span = chunk.spans[offset];
+        let line = match chunk.lines.get(offset) {
+            Some(&(line, _)) => line as usize,
+            None => sources.line_col(span.file_id, span.start).0,
+        };
+        if line > 0 && last_line != Some((span.file_id, line)) {
+            let text = sources.line_text(span.file_id, line).unwrap_or("").trim();
+            let _ = writeln!(out, "        ; {:>4} | {}", line, text);
+            last_line = Some((span.file_id, line));
+        }
+        let marker = if targets.contains(&offset) { '>' } else { ' ' };
+        let _ = writeln!(out, "  {:04} {} {}", offset, marker, instruction(module, chunk, op));
+    }
+}
+
+/// Komut adı ve işlenenleri; sabit havuzuna bakan işlenenlerin değeri parantez içinde gösterilir.
+fn instruction(module: &Module, chunk: &Chunk, op: &Op) -> String {
+    let debug = format!("{:?}", op);
+    let name = debug.split('(').next().unwrap_or(&debug);
+    let constant = |index: &u16| match chunk.constants.get(*index as usize) {
+        Some(c) => format!("{} ({})", index, constant_text(module, c)),
+        None => format!("{} (?)", index),
+    };
+    let operands = match op {
+        Op::Constant(i)
+        | Op::DefineGlobal(i)
+        | Op::GetGlobal(i)
+        | Op::SetGlobal(i)
+        | Op::GetField(i)
+        | Op::SetField(i)
+        | Op::Closure(i)
+        | Op::Class(i)
+        | Op::Inherit(i)
+        | Op::Method(i)
+        | Op::Field(i)
+        | Op::Import(i) => constant(i),
+        Op::GetLocal(slot) | Op::SetLocal(slot) | Op::GetUpvalue(slot) | Op::SetUpvalue(slot) => slot.to_string(),
+        Op::BuildList(n) | Op::BuildMap(n) | Op::Interpolate(n) => n.to_string(),
+        Op::Jump(target)
+        | Op::JumpIfFalse(target)
+        | Op::IterNext(target)
+        | Op::IterNextPair(target)
+        | Op::PushHandler(target) => format!("-> {:04}", target),
+        Op::Call(argc) => format!("argüman: {}", argc),
+        Op::CallGlobal(i, argc) | Op::Invoke(i, argc) | Op::SuperInvoke(i, argc) => format!("{} argüman: {}", constant(i), argc),
+        _ => String::new(),
+    };
+    if operands.is_empty() {
+        name.to_string()
+    } else {
+        format!("{:<14}{}", name, operands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::Compiler;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+    use crate::semantic::Resolver;
+    use crate::stackvm::StackVM;
+
+    fn disasm(code: &str) -> String {
+        let mut sources = SourceMap::new();
+        let file_id = sources.add("test.nova", code);
+        let tokens = Lexer::new(code).with_file(file_id).tokenize().unwrap();
+        let mut program = Parser::new(tokens).parse_program().unwrap();
+        Resolver::new(StackVM::new().known_globals()).resolve(&mut program).unwrap();
+        disassemble(&Compiler::new().compile(&program).unwrap(), &sources)
+    }
+
+    #[test]
+    fn lists_functions_constants_and_source_lines() {
+        let out = disasm("func f(n) { return n + 1; }\nexport let x = f(2);\n");
+        let headers: Vec<&str> = out.lines().filter(|line| line.starts_with("==")).collect();
+        assert_eq!(headers, ["== #0 <script> (parametre: 0) ==", "== #1 f (parametre: 1) =="]);
+        assert!(out.contains("  [  0] <fn f #1>\n"), "{}", out);
+        assert!(out.contains("        ;    2 | export let x = f(2);\n"), "{}", out);
+        assert!(out.contains("CallGlobal    1 (\"f\") argüman: 1\n"), "{}", out);
+        assert!(out.ends_with("\ndışa aktarılanlar: x\n"), "{}", out);
+    }
+
+    #[test]
+    fn marks_every_jump_target() {
+        let out = disasm("let i = 0;\nwhile (i < 3) { if (i == 1) { i = 5; } i = i + 1; }\n");
+        let mut jumps = 0;
+        for line in out.lines() {
+            if let Some(target) = line.split("-> ").nth(1) {
+                jumps += 1;
+                let marked = format!("  {} > ", target);
+                assert!(out.lines().any(|l| l.starts_with(&marked)), "{} işaretli değil:\n{}", target, out);
+            }
+        }
+        assert!(jumps >= 3, "{}", out);
+    }
+}
@@ -1,48 +1,161 @@
-// src/error.rs
-// NovaLang için kapsamlı hata yönetimi
-
-#[derive(Debug, Clone)]
-pub enum NovaErrorKind {
-    LexError,
-    ParseError,
-    SemanticError,
-    RuntimeError,
-}
-
-#[derive(Debug, Clone)]
-pub struct NovaError {
-    pub kind: NovaErrorKind,
-    pub message: String,
-    pub line: usize,
-    pub col: usize,
-    pub snippet: Option<String>,
-    pub token: Option<String>,
-}
-
-impl NovaError {
-    pub fn new(kind: NovaErrorKind, message: &str, line: usize, col: usize, snippet: Option<String>) -> Self {
-        NovaError {
-            kind,
-            message: message.to_string(),
-            line,
-            col,
-            snippet,
-            token: None,
-        }
-    }
-
-    pub fn with_token(mut self, token: &str) -> Self {
-        self.token = Some(token.to_string());
-        self
-    }
-
-    pub fn display(&self) {
-        eprintln!("[{:?}] {} (satır {}, sütun {})", self.kind, self.message, self.line, self.col);
-        if let Some(ref token) = self.token {
-            eprintln!("Token: {}", token);
-        }
-        if let Some(ref snip) = self.snippet {
-            eprintln!("--> {}", snip);
-        }
-    }
-} 
\ No newline at end of file
+// src/error.rs
+// NovaLang için kapsamlı hata yönetimi
+
+use crate::span::{SourceMap, Span};
+use crate::vm::Value;
+use std::ops::{Deref, DerefMut};
+
+const STACK_SHOWN: usize = 16;
+
+#[derive(Debug, Clone)]
+pub enum NovaErrorKind {
+    LexError,
+    ParseError,
+    SemanticError,
+    RuntimeError,
+    // İşlenen türleri operatöre uymuyor: `"a" - 1`
+    TypeError,
+    // Nova kodunda `throw` ile fırlatılan değer
+    Error,
+    // Fırlatılan nesnenin sınıfı: `throw MyError()`
+    Custom(String),
+}
+
+/// Hata bilgisi kutu içinde taşınır; böylece her `Result<_, NovaError>` yalnızca bir
+/// işaretçi kadar yer tutar. Alanlara `e.message` gibi doğrudan erişilir.
+#[derive(Debug, Clone)]
+pub struct NovaError(Box<ErrorInfo>);
+
+#[derive(Debug, Clone)]
+pub struct ErrorInfo {
+    pub kind: NovaErrorKind,
+    pub message: String,
+    pub line: usize,
+    pub col: usize,
+    pub snippet: Option<String>,
+    pub token: Option<String>,
+    pub span: Option<Span>,
+    // Hatanın oluştuğu andaki çağrı yığını, en içteki çağrı başta: `fib (main.nova:4)`.
+    // Motor hatayı ilk gördüğünde doldurur; yeniden fırlatılan hata yığınını korur.
+    pub stack: Vec<String>,
+    // `throw` ile fırlatılan özgün değer; Nova kodunda `e.value`
+    pub value: Option<Value>,
+}
+
+impl NovaError {
+    pub fn new(kind: NovaErrorKind, message: &str, line: usize, col: usize, snippet: Option<String>) -> Self {
+        NovaError(Box::new(ErrorInfo {
+            kind,
+            message: message.to_string(),
+            line,
+            col,
+            snippet,
+            token: None,
+            span: None,
+            stack: Vec::new(),
+            value: None,
+        }))
+    }
+
+    /// Yalnızca kaynak aralığı bilinen hatalar; satır/sütun ve snippet
+    /// gösterim sırasında `SourceMap` üzerinden hesaplanır.
+    pub fn at(kind: NovaErrorKind, message: &str, span: Span) -> Self {
+        NovaError::new(kind, message, 0, 0, None).with_span(span)
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Span'den satır, sütun ve snippet bilgisini doldurur (zaten doluysa dokunmaz).
+    pub fn locate(mut self, sources: &SourceMap) -> Self {
+        if let Some(span) = self.span {
+            if self.line == 0 {
+                let (line, col) = sources.line_col(span.file_id, span.start);
+                self.line = line;
+                self.col = col;
+            }
+            if self.snippet.is_none() {
+                self.snippet = sources.line_text(span.file_id, self.line).map(str::to_string);
+            }
+        }
+        self
+    }
+
+    pub fn with_value(mut self, value: Value) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn with_token(mut self, token: &str) -> Self {
+        self.token = Some(token.to_string());
+        self
+    }
+
+    pub fn display(&self, sources: &SourceMap) {
+        let err = self.clone().locate(sources);
+        let file = err.span.and_then(|s| sources.file(s.file_id)).map(|f| format!("{}: ", f.name));
+        eprintln!("[{}] {} ({}satır {}, sütun {})", err.kind.name(), err.message, file.unwrap_or_default(), err.line, err.col);
+        if let Some(ref token) = err.token {
+            eprintln!("Token: {}", token);
+        }
+        if let Some(ref snip) = err.snippet {
+            eprintln!("--> {}", snip);
+            if err.col > 0 {
+                // Span aynı satırda kaldığı sürece tüm aralığın altı çizilir
+                let width = err.span
+                    .and_then(|s| sources.slice(s))
+                    .map_or(1, |text| text.lines().next().unwrap_or("").chars().count().max(1));
+                let width = width.min(snip.chars().count().saturating_sub(err.col - 1).max(1));
+                eprintln!("    {}{}", " ".repeat(err.col - 1), "^".repeat(width));
+            }
+        }
+        // Yalnızca üst düzeyde oluşan hatada yığın bilgi vermez
+        if err.stack.len() > 1 {
+            eprintln!("Çağrı yığını:");
+            // Derin özyinelemede yalnızca en içteki çerçeveler gösterilir
+            for frame in err.stack.iter().take(STACK_SHOWN) {
+                eprintln!("    {}", frame);
+            }
+            if err.stack.len() > STACK_SHOWN {
+                eprintln!("    ... {} çerçeve daha", err.stack.len() - STACK_SHOWN);
+            }
+        }
+    }
+} 
+
+impl Deref for NovaError {
+    type Target = ErrorInfo;
+
+    fn deref(&self) -> &ErrorInfo {
+        &self.0
+    }
+}
+
+impl DerefMut for NovaError {
+    fn deref_mut(&mut self) -> &mut ErrorInfo {
+        &mut self.0
+    }
+}
+
+impl NovaErrorKind {
+    /// Tür adı; Nova kodunda `e.kind` bu metni verir.
+    pub fn name(&self) -> String {
+        match self {
+            NovaErrorKind::Custom(class_name) => class_name.clone(),
+            kind => format!("{:?}", kind),
+        }
+    }
+
+    /// `catch (e: TypeError)` gibi türlü catch'te yazılabilen adlar.
+    pub fn is_name(name: &str) -> bool {
+        matches!(name, "LexError" | "ParseError" | "SemanticError" | "RuntimeError" | "TypeError" | "Error")
+    }
+}
+
+/// Çağrı yığınının bir satırı: fonksiyon adı ve o çerçevede çalışılan kaynak satırı.
+pub fn stack_frame(function: &str, sources: &SourceMap, file_id: usize, line: usize) -> String {
+    let file = sources.file(file_id).map_or("?", |f| f.name.as_str());
+    format!("{} ({}:{})", function, file, line)
+}
@@ -1,120 +1,682 @@
-// src/lexer.rs
-// NovaLang için basit bir lexer (tokenizer)
-
-use crate::token::{Token, TokenKind};
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum Token {
-    Let,
-    Func,
-    If,
-    Else,
-    While,
-    Return,
-    True,
-    False,
-    Null,
-    Identifier(String),
-    Int(i64),
-    Float(f64),
-    String(String),
-    LParen,
-    RParen,
-    LBrace,
-    RBrace,
-    Comma,
-    Semicolon,
-    Assign,
-    Plus,
-    Minus,
-    Star,
-    Slash,
-    Percent,
-    Eq,
-    Neq,
-    Lt,
-    Gt,
-    Le,
-    Ge,
-    Comment,
-    Eof,
-    LBracket,
-    RBracket,
-    Colon,
-    Import,
-    Arrow,
-    Class,
-    Extends,
-    New,
-    Export,
-    Async,
-    Await,
-    Finally,
-    Dot,
-}
-
-pub struct Lexer {
-    src: Vec<char>,
-    pos: usize,
-    line: usize,
-    col: usize,
-}
-
-impl Lexer {
-    pub fn new(input: &str) -> Self {
-        Lexer {
-            src: input.chars().collect(),
-            pos: 0,
-            line: 1,
-            col: 1,
-        }
-    }
-
-    fn peek(&self) -> Option<char> {
-        self.src.get(self.pos).copied()
-    }
-
-    fn advance(&mut self) -> Option<char> {
-        let ch = self.src.get(self.pos).copied();
-        if let Some(c) = ch {
-            self.pos += 1;
-            if c == '\n' {
-                self.line += 1;
-                self.col = 1;
-            } else {
-                self.col += 1;
-            }
-        }
-        ch
-    }
-
-    fn peek_next(&self) -> Option<char> {
-        self.src.get(self.pos + 1).copied()
-    }
-
-    pub fn next_token(&mut self) -> Token {
-        // Burada boşluk atlama, anahtar kelime eşleme, sayı/string/identifier/yorum ayrımı ve hata yönetimi olacak
-        match self.peek() {
-            Some('[') => return Token { kind: TokenKind::LBracket, line: self.line, col: self.col },
-            Some(']') => return Token { kind: TokenKind::RBracket, line: self.line, col: self.col },
-            Some('{') => return Token { kind: TokenKind::LBrace, line: self.line, col: self.col },
-            Some('}') => return Token { kind: TokenKind::RBrace, line: self.line, col: self.col },
-            Some(':') => return Token { kind: TokenKind::Colon, line: self.line, col: self.col },
-            Some(',') => return Token { kind: TokenKind::Comma, line: self.line, col: self.col },
-            Some('=') => {
-                if self.peek_next() == Some('>') {
-                    self.advance(); self.advance();
-                    return Token { kind: TokenKind::Arrow, line: self.line, col: self.col };
-                }
-            },
-            Some('.') => return Token { kind: TokenKind::Dot, line: self.line, col: self.col },
-            _ => Token {
-                kind: TokenKind::Eof,
-                line: self.line,
-                col: self.col,
-            },
-        }
-    }
-
-    // Tokenize etme fonksiyonları buraya eklenecek
-} 
\ No newline at end of file
+// src/lexer.rs
+// NovaLang için basit bir lexer (tokenizer)
+
+use crate::token::{TemplatePart, Token, TokenKind};
+use crate::error::{NovaError, NovaErrorKind};
+use crate::span::Span;
+use unicode_ident::{is_xid_continue, is_xid_start};
+
+/// Anahtar kelime tablosu. Dosya başındaki `// dil: tr` pragması ya da `--dil=tr`
+/// CLI bayrağı ile seçilir; iki tablo da aynı `TokenKind`'lara eşlenir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordSet {
+    English,
+    Turkish,
+}
+
+impl KeywordSet {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "en" => Some(KeywordSet::English),
+            "tr" => Some(KeywordSet::Turkish),
+            _ => None,
+        }
+    }
+
+    fn lookup(self, word: &str) -> Option<TokenKind> {
+        KEYWORDS.iter().find(|(_, en, tr)| word == self.pick(en, tr)).map(|(kind, _, _)| kind.clone())
+    }
+
+    /// Anahtar kelime token'ının bu tablodaki yazılışı (biçimlendirici ve hata mesajları için).
+    pub fn spelling(self, kind: &TokenKind) -> Option<&'static str> {
+        KEYWORDS.iter().find(|(k, _, _)| k == kind).map(|(_, en, tr)| self.pick(en, tr))
+    }
+
+    fn pick(self, en: &'static str, tr: &'static str) -> &'static str {
+        match self {
+            KeywordSet::English => en,
+            KeywordSet::Turkish => tr,
+        }
+    }
+}
+
+const KEYWORDS: &[(TokenKind, &str, &str)] = &[
+    (TokenKind::Let, "let", "değişken"),
+    (TokenKind::Func, "func", "fonksiyon"),
+    (TokenKind::If, "if", "eğer"),
+    (TokenKind::Else, "else", "değilse"),
+    (TokenKind::While, "while", "döngü"),
+    (TokenKind::For, "for", "için"),
+    (TokenKind::In, "in", "içinde"),
+    (TokenKind::Return, "return", "döndür"),
+    (TokenKind::Break, "break", "kır"),
+    (TokenKind::Continue, "continue", "devam"),
+    (TokenKind::True, "true", "doğru"),
+    (TokenKind::False, "false", "yanlış"),
+    (TokenKind::Null, "null", "boş"),
+    (TokenKind::Class, "class", "sınıf"),
+    (TokenKind::Extends, "extends", "genişletir"),
+    (TokenKind::New, "new", "yeni"),
+    (TokenKind::Import, "import", "içeaktar"),
+    (TokenKind::Export, "export", "dışaaktar"),
+    (TokenKind::Async, "async", "eşzamansız"),
+    (TokenKind::Await, "await", "bekle"),
+    (TokenKind::Try, "try", "dene"),
+    (TokenKind::Catch, "catch", "yakala"),
+    (TokenKind::Finally, "finally", "sonunda"),
+    (TokenKind::Throw, "throw", "fırlat"),
+];
+
+pub struct Lexer {
+    src: Vec<char>,
+    pos: usize,
+    offset: usize, // `pos` karakterinin byte konumu
+    file_id: usize,
+    line: usize,
+    col: usize,
+    keywords: KeywordSet,
+    // Dil pragması yalnızca ilk token'dan önce geçerlidir
+    pragma_allowed: bool,
+}
+
+impl Lexer {
+    pub fn new(input: &str) -> Self {
+        Self::with_keywords(input, KeywordSet::English)
+    }
+
+    pub fn with_keywords(input: &str, keywords: KeywordSet) -> Self {
+        Lexer {
+            src: input.chars().collect(),
+            pos: 0,
+            offset: 0,
+            file_id: 0,
+            line: 1,
+            col: 1,
+            keywords,
+            pragma_allowed: true,
+        }
+    }
+
+    /// Üretilen span'lerin hangi `SourceMap` dosyasına ait olduğunu belirtir.
+    pub fn with_file(mut self, file_id: usize) -> Self {
+        self.file_id = file_id;
+        self
+    }
+
+    /// Dosya pragmasıyla değişmiş olabilecek, etkin anahtar kelime tablosu.
+    pub fn keyword_set(&self) -> KeywordSet {
+        self.keywords
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let ch = self.src.get(self.pos).copied();
+        if let Some(c) = ch {
+            self.pos += 1;
+            self.offset += c.len_utf8();
+            if c == '\n' {
+                self.line += 1;
+                self.col = 1;
+            } else {
+                self.col += 1;
+            }
+        }
+        ch
+    }
+
+    fn peek_next(&self) -> Option<char> {
+        self.src.get(self.pos + 1).copied()
+    }
+
+    fn mark(&self) -> Mark {
+        Mark { line: self.line, col: self.col, offset: self.offset }
+    }
+
+    fn span_from(&self, start: Mark) -> Span {
+        Span::new(self.file_id, start.offset, self.offset)
+    }
+
+    /// Kaynağın tamamını token listesine çevirir; liste `Eof` ile biter.
+    pub fn tokenize(&mut self) -> Result<Vec<Token>, NovaError> {
+        let mut tokens = Vec::new();
+        loop {
+            let tok = self.next_token()?;
+            let done = tok.kind == TokenKind::Eof;
+            tokens.push(tok);
+            if done {
+                return Ok(tokens);
+            }
+        }
+    }
+
+    pub fn next_token(&mut self) -> Result<Token, NovaError> {
+        self.skip_whitespace_and_comments()?;
+        self.pragma_allowed = false;
+        let start = self.mark();
+        let kind = self.scan_token(start)?;
+        Ok(Token { kind, line: start.line, col: start.col, span: self.span_from(start) })
+    }
+
+    fn scan_token(&mut self, start: Mark) -> Result<TokenKind, NovaError> {
+        let ch = match self.peek() {
+            Some(c) => c,
+            None => return Ok(TokenKind::Eof),
+        };
+
+        if self.at_doc_comment() {
+            return Ok(self.read_doc_comment());
+        }
+        if ch.is_ascii_digit() {
+            return self.read_number();
+        }
+        if ch == 'r' && self.peek_next() == Some('"') {
+            self.advance(); // 'r'
+            return self.read_string(true);
+        }
+        if is_ident_start(ch) {
+            return Ok(self.read_identifier());
+        }
+        if ch == '"' {
+            return self.read_string(false);
+        }
+
+        self.advance();
+        let kind = match ch {
+            '(' => TokenKind::LParen,
+            ')' => TokenKind::RParen,
+            '[' => TokenKind::LBracket,
+            ']' => TokenKind::RBracket,
+            '{' => TokenKind::LBrace,
+            '}' => TokenKind::RBrace,
+            ',' => TokenKind::Comma,
+            ';' => TokenKind::Semicolon,
+            ':' => TokenKind::Colon,
+            '.' if self.match_char('.') => if self.match_char('=') { TokenKind::DotDotEq } else { TokenKind::DotDot },
+            '.' => TokenKind::Dot,
+            '+' => TokenKind::Plus,
+            '-' => TokenKind::Minus,
+            '*' => TokenKind::Star,
+            '/' => TokenKind::Slash,
+            '%' => TokenKind::Percent,
+            '=' => match self.peek() {
+                Some('=') => { self.advance(); TokenKind::Eq }
+                Some('>') => { self.advance(); TokenKind::Arrow }
+                _ => TokenKind::Assign,
+            },
+            '!' => if self.match_char('=') { TokenKind::Neq } else { TokenKind::Not },
+            '<' => if self.match_char('=') { TokenKind::Le } else { TokenKind::Lt },
+            '>' => if self.match_char('=') { TokenKind::Ge } else { TokenKind::Gt },
+            '&' if self.match_char('&') => TokenKind::And,
+            '|' if self.match_char('|') => TokenKind::Or,
+            _ => {
+                let message = format!("Tanınmayan karakter '{}'", ch);
+                return Err(self.error(&message, start, ch.to_string()));
+            }
+        };
+        Ok(kind)
+    }
+
+    /// Verilen konumdaki satırı snippet olarak ekleyerek LexError üretir.
+    fn error(&self, message: &str, at: Mark, text: String) -> NovaError {
+        let snippet = self.source_line(at.line);
+        let span = Span::new(self.file_id, at.offset, at.offset + text.len());
+        NovaError::new(NovaErrorKind::LexError, message, at.line, at.col, Some(snippet))
+            .with_token(&text)
+            .with_span(span)
+    }
+
+    fn source_line(&self, line: usize) -> String {
+        let text: String = self.src.iter().collect();
+        text.lines().nth(line - 1).unwrap_or("").to_string()
+    }
+
+    fn match_char(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace_and_comments(&mut self) -> Result<(), NovaError> {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.advance();
+            } else if c == '/' && self.peek_next() == Some('/') && !self.at_doc_comment() {
+                let mut text = String::new();
+                while let Some(c) = self.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+                    text.push(c);
+                    self.advance();
+                }
+                if self.pragma_allowed {
+                    self.apply_pragma(&text);
+                }
+            } else if c == '/' && self.peek_next() == Some('*') {
+                self.skip_block_comment()?;
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// `/* ... */` yorumlarını iç içe geçmiş olanlarla birlikte atlar.
+    fn skip_block_comment(&mut self) -> Result<(), NovaError> {
+        let start = self.mark();
+        let mut depth = 0;
+        loop {
+            match (self.peek(), self.peek_next()) {
+                (Some('/'), Some('*')) => {
+                    self.advance();
+                    self.advance();
+                    depth += 1;
+                }
+                (Some('*'), Some('/')) => {
+                    self.advance();
+                    self.advance();
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                (Some(_), _) => {
+                    self.advance();
+                }
+                (None, _) => return Err(self.error("Kapatılmamış yorum bloğu", start, "/*".to_string())),
+            }
+        }
+    }
+
+    /// `///` ile başlayan (ama `////` olmayan) satırlar doc yorumudur.
+    fn at_doc_comment(&self) -> bool {
+        (0..3).all(|i| self.src.get(self.pos + i) == Some(&'/')) && self.src.get(self.pos + 3) != Some(&'/')
+    }
+
+    fn read_doc_comment(&mut self) -> TokenKind {
+        for _ in 0..3 {
+            self.advance();
+        }
+        let mut text = String::new();
+        while let Some(c) = self.peek().filter(|c| *c != '\n') {
+            text.push(c);
+            self.advance();
+        }
+        let text = text.strip_prefix(' ').unwrap_or(&text).trim_end().to_string();
+        TokenKind::Comment(text)
+    }
+
+    /// `// dil: tr` veya `// dil: en` yorumu dosyanın anahtar kelime tablosunu değiştirir.
+    fn apply_pragma(&mut self, comment: &str) {
+        let body = comment.trim_start_matches('/').trim();
+        if let Some(name) = body.strip_prefix("dil:") {
+            if let Some(set) = KeywordSet::from_name(name.trim()) {
+                self.keywords = set;
+            }
+        }
+    }
+
+    /// Ondalık, `0x`/`0b`/`0o` önekli, `_` ayraçlı ve üslü sayı literallerini okur.
+    fn read_number(&mut self) -> Result<TokenKind, NovaError> {
+        let start = self.mark();
+        let first = self.pos;
+        let radix = match (self.peek(), self.peek_next()) {
+            (Some('0'), Some('x' | 'X')) => 16,
+            (Some('0'), Some('b' | 'B')) => 2,
+            (Some('0'), Some('o' | 'O')) => 8,
+            _ => 10,
+        };
+        if radix != 10 {
+            self.advance();
+            self.advance();
+            let digits = self.read_digits(radix);
+            self.consume_trailing_garbage();
+            let text: String = self.src[first..self.pos].iter().collect();
+            if self.pos != first + 2 + digits.len() || !valid_separators(&digits) {
+                return Err(self.error("Geçersiz sayı", start, text));
+            }
+            return self.parse_int(&digits, radix, start, text);
+        }
+
+        let int_part = self.read_digits(10);
+        let mut ok = valid_separators(&int_part);
+        let mut is_float = false;
+        // `1.5` float'tır; `0..n` gibi aralıklarda nokta sayıya dahil edilmez
+        if self.peek() == Some('.') && matches!(self.peek_next(), Some(c) if c.is_ascii_digit()) {
+            self.advance();
+            is_float = true;
+            ok &= valid_separators(&self.read_digits(10));
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            let sign = matches!(self.peek_next(), Some('+' | '-'));
+            let digit_at = self.pos + if sign { 2 } else { 1 };
+            if matches!(self.src.get(digit_at), Some(c) if c.is_ascii_digit()) {
+                self.advance();
+                if sign {
+                    self.advance();
+                }
+                is_float = true;
+                ok &= valid_separators(&self.read_digits(10));
+            }
+        }
+        let garbage = self.consume_trailing_garbage();
+        let text: String = self.src[first..self.pos].iter().collect();
+        if !ok || garbage {
+            return Err(self.error("Geçersiz sayı", start, text));
+        }
+        let clean: String = text.chars().filter(|c| *c != '_').collect();
+        if is_float {
+            match clean.parse::<f64>() {
+                Ok(f) if f.is_finite() => Ok(TokenKind::Float(f)),
+                _ => Err(self.error("Ondalık sayı çok büyük", start, text)),
+            }
+        } else {
+            self.parse_int(&clean, 10, start, text)
+        }
+    }
+
+    /// Verilen tabandaki rakamları ve `_` ayraçlarını okur.
+    fn read_digits(&mut self, radix: u32) -> String {
+        let mut digits = String::new();
+        while let Some(c) = self.peek().filter(|c| c.is_digit(radix) || *c == '_') {
+            digits.push(c);
+            self.advance();
+        }
+        digits
+    }
+
+    /// `12abc` gibi sayıya bitişik harf ve rakamları tüketir; tüketildiyse `true` döner.
+    fn consume_trailing_garbage(&mut self) -> bool {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if is_xid_continue(c)) {
+            self.advance();
+        }
+        self.pos != start
+    }
+
+    fn parse_int(&self, digits: &str, radix: u32, start: Mark, text: String) -> Result<TokenKind, NovaError> {
+        let clean: String = digits.chars().filter(|c| *c != '_').collect();
+        i64::from_str_radix(&clean, radix)
+            .map(TokenKind::Int)
+            .map_err(|_| self.error("Tamsayı 64 bit sınırını aşıyor", start, text))
+    }
+
+    /// Tanımlayıcılar Unicode XID_Start/XID_Continue kurallarına uyar (`öğrenci`, `sayaç`, `ĞİŞ`).
+    fn read_identifier(&mut self) -> TokenKind {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if is_xid_continue(c)) {
+            self.advance();
+        }
+        let text: String = self.src[start..self.pos].iter().collect();
+        self.keywords.lookup(&text).unwrap_or(TokenKind::Identifier(text))
+    }
+
+    /// `"..."`, `r"..."` ve `"""..."""` biçimlerini okur. Ham (raw) stringlerde
+    /// kaçış dizileri ve `${...}` interpolasyonu işlenmez; üçlü tırnaklı stringler
+    /// birden çok satıra yayılabilir.
+    fn read_string(&mut self, raw: bool) -> Result<TokenKind, NovaError> {
+        let start = self.mark();
+        let triple = self.peek_next() == Some('"') && self.src.get(self.pos + 2) == Some(&'"');
+        let quotes = if triple { 3 } else { 1 };
+        for _ in 0..quotes {
+            self.advance();
+        }
+        // Açılış tırnaklarının hemen ardından gelen satır sonu içeriğe dahil edilmez
+        if triple && self.peek() == Some('\n') {
+            self.advance();
+        }
+        let mut value = String::new();
+        let mut parts = Vec::new();
+        loop {
+            match self.peek() {
+                Some('"') if !triple || self.at_triple_quote() => {
+                    for _ in 0..quotes {
+                        self.advance();
+                    }
+                    if parts.is_empty() {
+                        return Ok(TokenKind::String(value));
+                    }
+                    if !value.is_empty() {
+                        parts.push(TemplatePart::Text(value));
+                    }
+                    return Ok(TokenKind::Template(parts));
+                }
+                Some('\n') if !triple => break,
+                Some('$') if !raw && self.peek_next() == Some('{') => {
+                    if !value.is_empty() {
+                        parts.push(TemplatePart::Text(std::mem::take(&mut value)));
+                    }
+                    parts.push(TemplatePart::Code(self.read_interpolation()?));
+                }
+                Some('\\') if !raw => value.push(self.read_escape()?),
+                Some(c) => {
+                    self.advance();
+                    value.push(c);
+                }
+                None => break,
+            }
+        }
+        Err(self.error("Kapatılmamış string", start, "\"".to_string()))
+    }
+
+    /// `${` ile başlayan gömülü ifadenin tokenlarını eşleşen `}` bulunana kadar okur.
+    fn read_interpolation(&mut self) -> Result<Vec<Token>, NovaError> {
+        let start = self.mark();
+        self.advance(); // '$'
+        self.advance(); // '{'
+        let mut tokens = Vec::new();
+        let mut depth = 0;
+        loop {
+            let tok = self.next_token()?;
+            match tok.kind {
+                TokenKind::Eof => {
+                    return Err(self.error("Kapatılmamış interpolasyon", start, "${".to_string()));
+                }
+                TokenKind::LBrace => depth += 1,
+                TokenKind::RBrace if depth == 0 => {
+                    if tokens.is_empty() {
+                        return Err(self.error("Boş interpolasyon", start, "${}".to_string()));
+                    }
+                    let span = Span::new(self.file_id, tok.span.start, tok.span.start);
+                    tokens.push(Token { kind: TokenKind::Eof, line: tok.line, col: tok.col, span });
+                    return Ok(tokens);
+                }
+                TokenKind::RBrace => depth -= 1,
+                _ => {}
+            }
+            tokens.push(tok);
+        }
+    }
+
+    fn at_triple_quote(&self) -> bool {
+        (0..3).all(|i| self.src.get(self.pos + i) == Some(&'"'))
+    }
+
+    fn read_escape(&mut self) -> Result<char, NovaError> {
+        let start = self.mark();
+        self.advance(); // '\\'
+        let c = match self.advance() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('0') => '\0',
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('$') => '$',
+            Some('u') if self.peek() == Some('{') => {
+                self.advance();
+                let mut hex = String::new();
+                while let Some(c) = self.peek().filter(|c| c.is_ascii_hexdigit()) {
+                    hex.push(c);
+                    self.advance();
+                }
+                let closed = self.match_char('}');
+                let code = u32::from_str_radix(&hex, 16).ok().filter(|_| closed && hex.len() <= 6);
+                return code.and_then(char::from_u32).ok_or_else(|| {
+                    let text = format!("\\u{{{}{}", hex, if closed { "}" } else { "" });
+                    self.error("Geçersiz unicode kaçış dizisi", start, text)
+                });
+            }
+            Some(c) => {
+                let text = format!("\\{}", c);
+                return Err(self.error("Geçersiz kaçış dizisi", start, text));
+            }
+            None => return Err(self.error("Kapatılmamış string", start, "\\".to_string())),
+        };
+        Ok(c)
+    }
+}
+
+/// Bir hata ya da token başlangıcı için kaydedilen konum.
+#[derive(Clone, Copy)]
+struct Mark {
+    line: usize,
+    col: usize,
+    offset: usize,
+}
+
+fn is_ident_start(c: char) -> bool {
+    c == '_' || is_xid_start(c)
+}
+
+/// `_` ayraçları yalnızca iki rakamın arasında kullanılabilir.
+fn valid_separators(digits: &str) -> bool {
+    !digits.is_empty() && !digits.starts_with('_') && !digits.ends_with('_') && !digits.contains("__")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(input: &str) -> Vec<TokenKind> {
+        let mut tokens = Lexer::new(input).tokenize().unwrap();
+        assert_eq!(tokens.pop().map(|t| t.kind), Some(TokenKind::Eof));
+        tokens.into_iter().map(|t| t.kind).collect()
+    }
+
+    fn ident(name: &str) -> TokenKind {
+        TokenKind::Identifier(name.to_string())
+    }
+
+    fn lex_error(input: &str) -> NovaError {
+        let e = Lexer::new(input).tokenize().unwrap_err();
+        assert!(matches!(e.kind, NovaErrorKind::LexError), "{:?}", e.kind);
+        e
+    }
+
+    #[test]
+    fn tokenizes_operators_and_keywords() {
+        use TokenKind::*;
+        assert_eq!(
+            kinds("let f = (a) => a <= 2 && !b || c != d; for i in 0..=3 {} x.y[1] % 2 >= -1;"),
+            vec![
+                Let, ident("f"), Assign, LParen, ident("a"), RParen, Arrow, ident("a"), Le, Int(2), And, Not, ident("b"),
+                Or, ident("c"), Neq, ident("d"), Semicolon, For, ident("i"), In, Int(0), DotDotEq, Int(3), LBrace,
+                RBrace, ident("x"), Dot, ident("y"), LBracket, Int(1), RBracket, Percent, Int(2), Ge, Minus, Int(1),
+                Semicolon,
+            ]
+        );
+        assert_eq!(
+            kinds("try {} catch (e: T) {} finally {} throw class A extends B {} async await new import export"),
+            vec![
+                Try, LBrace, RBrace, Catch, LParen, ident("e"), Colon, ident("T"), RParen, LBrace, RBrace, Finally,
+                LBrace, RBrace, Throw, Class, ident("A"), Extends, ident("B"), LBrace, RBrace, Async, Await, New,
+                Import, Export,
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_lex_errors_with_position() {
+        let e = lex_error("let a = 1;\nlet b = #;");
+        assert_eq!((e.line, e.col), (2, 9));
+        assert_eq!(e.token.as_deref(), Some("#"));
+        assert_eq!(e.snippet.as_deref(), Some("let b = #;"));
+        lex_error("\"açık");
+        lex_error("/* kapanmayan /* iç */");
+        lex_error("a & b");
+    }
+
+    #[test]
+    fn reads_escapes_raw_and_multiline_strings() {
+        assert_eq!(kinds(r#""a\n\t\"\\\$\u{15F}""#), vec![TokenKind::String("a\n\t\"\\$ş".to_string())]);
+        assert_eq!(kinds(r#"r"C:\yol\${x}""#), vec![TokenKind::String(r"C:\yol\${x}".to_string())]);
+        assert_eq!(kinds("\"\"\"\nbir\n\"iki\"\n\"\"\""), vec![TokenKind::String("bir\n\"iki\"\n".to_string())]);
+        lex_error(r#""\q""#);
+        lex_error(r#""\u{110000}""#);
+        lex_error("\"satır\nsonu\"");
+    }
+
+    #[test]
+    fn splits_interpolated_strings() {
+        let parts = match kinds(r#""Merhaba ${name}! ${ {"a": 1}["a"] }""#).remove(0) {
+            TokenKind::Template(parts) => parts,
+            other => panic!("şablon bekleniyordu: {:?}", other),
+        };
+        let code = |part: &TemplatePart| match part {
+            TemplatePart::Code(tokens) => tokens.iter().map(|t| t.kind.clone()).collect::<Vec<_>>(),
+            TemplatePart::Text(text) => panic!("kod bekleniyordu: {}", text),
+        };
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[0], TemplatePart::Text("Merhaba ".to_string()));
+        // Gömülü ifadenin tokenları ayrı ayrı ayrıştırılabilsin diye Eof ile biter
+        assert_eq!(code(&parts[1]), vec![ident("name"), TokenKind::Eof]);
+        assert_eq!(parts[2], TemplatePart::Text("! ".to_string()));
+        assert_eq!(code(&parts[3]).len(), 9);
+        lex_error("\"${a\"");
+    }
+
+    #[test]
+    fn reads_numeric_literals() {
+        use TokenKind::*;
+        assert_eq!(
+            kinds("0xFF 0b1010 0o17 1_000_000 1.5e-3 2E10 3.25 1e+2 0x7FFF_FFFF_FFFF_FFFF"),
+            vec![Int(255), Int(10), Int(15), Int(1_000_000), Float(1.5e-3), Float(2e10), Float(3.25), Float(100.0), Int(i64::MAX)]
+        );
+        assert_eq!(kinds("0..n"), vec![Int(0), DotDot, ident("n")]);
+        for bad in ["12abc", "1__0", "1_", "0x", "0b102", "9223372036854775808", "1e999"] {
+            lex_error(bad);
+        }
+    }
+
+    #[test]
+    fn reads_unicode_identifiers() {
+        assert_eq!(kinds("öğrenci sayaç ĞİŞ _x1"), vec![ident("öğrenci"), ident("sayaç"), ident("ĞİŞ"), ident("_x1")]);
+    }
+
+    #[test]
+    fn turkish_keywords_follow_the_pragma() {
+        use TokenKind::*;
+        let mut lexer = Lexer::new("// dil: tr\ndeğişken let = doğru; eğer değilse fırlat");
+        let tokens: Vec<_> = lexer.tokenize().unwrap().into_iter().map(|t| t.kind).collect();
+        assert_eq!(tokens, vec![Let, ident("let"), Assign, True, Semicolon, If, Else, Throw, Eof]);
+        assert_eq!(lexer.keyword_set(), KeywordSet::Turkish);
+        // Pragma yalnızca ilk token'dan önce geçerlidir
+        assert_eq!(kinds("let x; // dil: tr\ndeğişken"), vec![Let, ident("x"), Semicolon, ident("değişken")]);
+        assert_eq!(KeywordSet::Turkish.spelling(&Let), Some("değişken"));
+    }
+
+    #[test]
+    fn skips_block_comments_and_keeps_doc_comments() {
+        use TokenKind::*;
+        assert_eq!(
+            kinds("a /* dış /* iç */ hâlâ yorum */ b // satır\n//// değil\n/// Belge metni  \nfunc"),
+            vec![ident("a"), ident("b"), Comment("Belge metni".to_string()), Func]
+        );
+    }
+
+    #[test]
+    fn spans_are_byte_offsets() {
+        let tokens = Lexer::new("ş = \"ğü\";\n  x").with_file(3).tokenize().unwrap();
+        let spans: Vec<_> = tokens.iter().map(|t| (t.span.start, t.span.end)).collect();
+        assert_eq!(spans, vec![(0, 2), (3, 4), (5, 11), (11, 12), (15, 16), (16, 16)]);
+        assert!(tokens.iter().all(|t| t.span.file_id == 3));
+        assert_eq!((tokens[4].line, tokens[4].col), (2, 3));
+    }
+}
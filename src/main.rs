@@ -1,73 +1,339 @@
-// src/main.rs
-// NovaLang ana giriş noktası: REPL ve dosya çalıştırıcı
-
-mod token;
-mod lexer;
-mod parser;
-mod ast;
-mod semantic;
-mod vm;
-mod runtime;
-mod error;
-
-use std::env;
-use std::fs;
-use std::io::{self, Write};
-use crate::lexer::Lexer;
-use crate::parser::Parser;
-use crate::semantic::SemanticAnalyzer;
-use crate::vm::VM;
-use crate::error::NovaError;
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() > 1 {
-        // Dosya çalıştırıcı
-        let filename = &args[1];
-        let code = fs::read_to_string(filename).expect("Dosya okunamadı");
-        if let Err(e) = run_code(&code) {
-            e.display();
-        }
-    } else {
-        // REPL
-        repl();
-    }
-}
-
-fn run_code(code: &str) -> Result<(), NovaError> {
-    let mut lexer = Lexer::new(code);
-    let mut tokens = Vec::new();
-    loop {
-        let tok = lexer.next_token();
-        if let crate::token::TokenKind::Eof = tok.kind {
-            break;
-        }
-        tokens.push(tok);
-    }
-    let mut parser = Parser::new(tokens);
-    let program = parser.parse_program()?;
-    let mut analyzer = SemanticAnalyzer::new();
-    analyzer.analyze(&program)?;
-    let mut vm = VM::new();
-    vm.run(&program)?;
-    Ok(())
-}
-
-fn repl() {
-    println!("NovaLang REPL'e hoş geldiniz! (Çıkmak için 'exit')");
-    let mut input = String::new();
-    loop {
-        print!("> ");
-        io::stdout().flush().unwrap();
-        input.clear();
-        if io::stdin().read_line(&mut input).is_err() {
-            break;
-        }
-        if input.trim() == "exit" {
-            break;
-        }
-        if let Err(e) = run_code(&input) {
-            e.display();
-        }
-    }
+// src/main.rs
+// NovaLang ana giriş noktası: REPL ve dosya çalıştırıcı
+
+mod token;
+mod lexer;
+mod parser;
+mod ast;
+mod semantic;
+mod vm;
+mod runtime;
+mod error;
+mod span;
+mod bytecode;
+mod compiler;
+mod stackvm;
+mod arith;
+mod disasm;
+mod novac;
+
+use std::collections::{BTreeMap, HashSet};
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::thread;
+use crate::lexer::{KeywordSet, Lexer};
+use crate::token::TokenKind;
+use crate::parser::Parser;
+use crate::semantic::{Resolver, SemanticAnalyzer};
+use crate::vm::VM;
+use crate::stackvm::StackVM;
+use crate::compiler::Compiler;
+use crate::bytecode::Module;
+use crate::error::NovaError;
+use crate::ast::{collect_docs, Program, Stmt};
+use crate::span::SourceMap;
+
+/// Programı çalıştıran motor: varsayılan bytecode, `--motor=agac` ile ağaç yorumlayıcı.
+enum Engine {
+    Tree(VM),
+    Bytecode(StackVM),
+}
+
+impl Engine {
+    fn new(bytecode: bool) -> Self {
+        if bytecode {
+            Engine::Bytecode(StackVM::new())
+        } else {
+            Engine::Tree(VM::new())
+        }
+    }
+
+    fn sources(&mut self) -> &mut SourceMap {
+        match self {
+            Engine::Tree(vm) => &mut vm.sources,
+            Engine::Bytecode(vm) => &mut vm.sources,
+        }
+    }
+
+    fn known_globals(&self) -> HashSet<String> {
+        match self {
+            Engine::Tree(vm) => vm.known_globals(),
+            Engine::Bytecode(vm) => vm.known_globals(),
+        }
+    }
+
+    fn run(&mut self, program: &Program) -> Result<(), NovaError> {
+        match self {
+            Engine::Tree(vm) => vm.run(program),
+            Engine::Bytecode(vm) => vm.run(Compiler::new().compile(program)?),
+        }
+    }
+}
+
+fn main() {
+    // Ağaç yorumlayıcı her Nova çağrısında Rust yığınında da derinleşir; seçildiyse program
+    // FRAMES_MAX çerçeveye yetecek kadar yığını olan bir iş parçacığında çalışır
+    if !env::args().any(|arg| arg == "--motor=agac") {
+        return run_cli();
+    }
+    let worker = thread::Builder::new().stack_size(vm::STACK_BYTES).spawn(run_cli).expect("İş parçacığı başlatılamadı");
+    if worker.join().is_err() {
+        std::process::exit(101);
+    }
+}
+
+fn run_cli() {
+    let mut keywords = KeywordSet::English;
+    let mut bytecode = true;
+    let mut files = Vec::new();
+    for arg in env::args().skip(1) {
+        if let Some(name) = arg.strip_prefix("--motor=") {
+            // Çalıştırma motoru: --motor=bytecode (varsayılan) veya --motor=agac
+            bytecode = match name {
+                "bytecode" => true,
+                "agac" => false,
+                _ => {
+                    eprintln!("Bilinmeyen motor: {} (bytecode veya agac olmalı)", name);
+                    std::process::exit(2);
+                }
+            };
+        } else if let Some(name) = arg.strip_prefix("--dil=") {
+            // Anahtar kelime dili: --dil=tr veya --dil=en (dosya pragması önceliklidir)
+            keywords = KeywordSet::from_name(name).unwrap_or_else(|| {
+                eprintln!("Bilinmeyen dil: {} (tr veya en olmalı)", name);
+                std::process::exit(2);
+            });
+        } else {
+            files.push(arg);
+        }
+    }
+    match files.first().map(String::as_str) {
+        // Derlenen bytecode'u çalıştırmadan yazdırır: nova disasm dosya.nova
+        Some("disasm") => disasm_file(command_arg(&files, "disasm"), keywords),
+        // Derlenmiş modülü yazar: nova build dosya.nova -> dosya.novac
+        Some("build") => build_file(command_arg(&files, "build"), keywords),
+        Some("run") => run_file(command_arg(&files, "run"), keywords, bytecode),
+        // Dosya çalıştırıcı
+        Some(filename) => run_file(filename, keywords, bytecode),
+        // REPL
+        None => repl(keywords, bytecode),
+    }
+}
+
+fn command_arg<'a>(files: &'a [String], command: &str) -> &'a str {
+    files.get(1).map(String::as_str).unwrap_or_else(|| {
+        eprintln!("Kullanım: nova {} <dosya>", command);
+        std::process::exit(2);
+    })
+}
+
+fn is_compiled(filename: &str) -> bool {
+    Path::new(filename).extension().is_some_and(|ext| ext == "novac")
+}
+
+/// `.nova` kaynağını ya da `.novac` derlenmiş modülünü çalıştırır.
+fn run_file(filename: &str, keywords: KeywordSet, bytecode: bool) {
+    if is_compiled(filename) {
+        if !bytecode {
+            eprintln!(".novac dosyaları yalnızca bytecode motoruyla çalıştırılabilir");
+            std::process::exit(2);
+        }
+        let mut vm = StackVM::new();
+        match novac::load(Path::new(filename), &mut vm.sources) {
+            Ok(module) => {
+                if let Err(e) = vm.run(module) {
+                    report(&vm.sources, &[e]);
+                    std::process::exit(1);
+                }
+            }
+            Err(msg) => {
+                eprintln!("{}: {}", filename, msg);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    let code = fs::read_to_string(filename).expect("Dosya okunamadı");
+    let mut engine = Engine::new(bytecode);
+    if let Err(errors) = run_code(&mut engine, filename, &code, keywords) {
+        report(engine.sources(), &errors);
+        std::process::exit(1);
+    }
+}
+
+fn build_file(filename: &str, keywords: KeywordSet) {
+    let code = fs::read_to_string(filename).expect("Dosya okunamadı");
+    let mut sources = SourceMap::new();
+    let module = match compile_code(&mut sources, filename, &code, keywords) {
+        Ok(module) => module,
+        Err(errors) => {
+            report(&sources, &errors);
+            std::process::exit(1);
+        }
+    };
+    let stamp = novac::Stamp::of(Path::new(filename)).unwrap_or_default();
+    let output = Path::new(filename).with_extension("novac");
+    let bytes = novac::encode(&module, &sources, filename, stamp);
+    if let Err(e) = fs::write(&output, bytes) {
+        eprintln!("{} yazılamadı: {}", output.display(), e);
+        std::process::exit(1);
+    }
+}
+
+fn disasm_file(filename: &str, keywords: KeywordSet) {
+    let mut sources = SourceMap::new();
+    if is_compiled(filename) {
+        match novac::load(Path::new(filename), &mut sources) {
+            Ok(module) => print!("{}", disasm::disassemble(&module, &sources)),
+            Err(msg) => {
+                eprintln!("{}: {}", filename, msg);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    let code = fs::read_to_string(filename).expect("Dosya okunamadı");
+    match compile_code(&mut sources, filename, &code, keywords) {
+        Ok(module) => print!("{}", disasm::disassemble(&module, &sources)),
+        Err(errors) => {
+            report(&sources, &errors);
+            std::process::exit(1);
+        }
+    }
+}
+
+// Parser hatadan sonra toparlanır; dosyadaki tüm sözdizimi hataları birlikte döner
+fn parse_code(sources: &mut SourceMap, name: &str, code: &str, keywords: KeywordSet) -> Result<Program, Vec<NovaError>> {
+    let file_id = sources.add(name, code);
+    let mut lexer = Lexer::with_keywords(code, keywords).with_file(file_id);
+    let tokens = lexer.tokenize().map_err(|e| vec![e])?;
+    let mut parser = Parser::new(tokens).with_keywords(lexer.keyword_set());
+    parser.parse_program()
+}
+
+fn run_code(engine: &mut Engine, name: &str, code: &str, keywords: KeywordSet) -> Result<(), Vec<NovaError>> {
+    let mut program = parse_code(engine.sources(), name, code, keywords)?;
+    run_program(engine, &mut program)
+}
+
+/// Kaynağı çalıştırmadan bytecode modülüne derler; isim çözümü yalnızca yerleşiklerle yapılır.
+fn compile_code(sources: &mut SourceMap, name: &str, code: &str, keywords: KeywordSet) -> Result<Module, Vec<NovaError>> {
+    let mut program = parse_code(sources, name, code, keywords)?;
+    Resolver::new(StackVM::new().known_globals()).resolve(&mut program)?;
+    SemanticAnalyzer::new().analyze(&program).map_err(|e| vec![e])?;
+    Compiler::new().compile(&program).map_err(|e| vec![e])
+}
+
+fn report(sources: &SourceMap, errors: &[NovaError]) {
+    for e in errors {
+        e.display(sources);
+    }
+    if errors.len() > 1 {
+        eprintln!("{} hata bulundu", errors.len());
+    }
+}
+
+fn run_program(engine: &mut Engine, program: &mut Program) -> Result<(), Vec<NovaError>> {
+    Resolver::new(engine.known_globals()).resolve(program)?;
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.analyze(program).map_err(|e| vec![e])?;
+    engine.run(program).map_err(|e| vec![e])
+}
+
+fn repl(keywords: KeywordSet, bytecode: bool) {
+    println!("NovaLang REPL'e hoş geldiniz! (Çıkmak için 'exit', belgeler için ':help [isim]')");
+    // `///` ile belgelenmiş tanımlar oturum boyunca saklanır
+    let mut docs: BTreeMap<String, String> = BTreeMap::new();
+    let mut engine = Engine::new(bytecode);
+    let mut input = String::new();
+    loop {
+        print!("{}", if input.is_empty() { "> " } else { "... " });
+        io::stdout().flush().unwrap();
+        let start = input.len();
+        match io::stdin().read_line(&mut input) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        if start == 0 {
+            if input.trim() == "exit" {
+                break;
+            }
+            if let Some(topic) = input.trim().strip_prefix(":help") {
+                show_help(&docs, topic.trim());
+                input.clear();
+                continue;
+            }
+        }
+        // Açık kalan blok ya da tanımını bekleyen doc yorumu sonraki satırlarla tamamlanır
+        if needs_more(&input, keywords) {
+            continue;
+        }
+        let result = parse_code(engine.sources(), "<repl>", &input, keywords).and_then(|mut program| {
+            docs.extend(collect_docs(&program));
+            run_program(&mut engine, &mut program)?;
+            docs.extend(import_docs(&program, keywords));
+            Ok(())
+        });
+        if let Err(errors) = result {
+            report(engine.sources(), &errors);
+        }
+        input.clear();
+    }
+}
+
+/// Girdi kapanmamış `(`, `[` ya da `{` ile veya henüz bir tanıma bağlanmamış `///`
+/// yorumuyla bitiyorsa REPL sonraki satırı bekler. Lexer hatası olan girdi beklemeden
+/// çalıştırılır ki hata hemen raporlansın.
+fn needs_more(input: &str, keywords: KeywordSet) -> bool {
+    let tokens = match Lexer::with_keywords(input, keywords).tokenize() {
+        Ok(tokens) => tokens,
+        Err(_) => return false,
+    };
+    let mut depth = 0i32;
+    for token in &tokens {
+        match token.kind {
+            TokenKind::LParen | TokenKind::LBracket | TokenKind::LBrace => depth += 1,
+            TokenKind::RParen | TokenKind::RBracket | TokenKind::RBrace => depth -= 1,
+            _ => {}
+        }
+    }
+    let last = tokens.iter().rev().find(|token| token.kind != TokenKind::Eof);
+    depth > 0 || matches!(last.map(|token| &token.kind), Some(TokenKind::Comment(_)))
+}
+
+/// `import` edilen modüllerin kaynağındaki doc yorumları; motor modülü zaten yüklediği
+/// için kaynak yalnızca belgeler için ayrıca okunur.
+fn import_docs(program: &Program, keywords: KeywordSet) -> Vec<(String, String)> {
+    let mut docs = Vec::new();
+    for stmt in &program.body {
+        if let Stmt::Import { name, .. } = stmt {
+            let filename = format!("{}.nova", name);
+            let code = match fs::read_to_string(&filename) {
+                Ok(code) => code,
+                Err(_) => continue,
+            };
+            if let Ok(module) = parse_code(&mut SourceMap::new(), &filename, &code, keywords) {
+                docs.extend(collect_docs(&module));
+            }
+        }
+    }
+    docs
+}
+
+fn show_help(docs: &BTreeMap<String, String>, topic: &str) {
+    if topic.is_empty() {
+        if docs.is_empty() {
+            println!("Belgelenmiş tanım yok. Tanımların önüne `///` yorumu ekleyin.");
+        }
+        for name in docs.keys() {
+            println!("  {}", name);
+        }
+        return;
+    }
+    match docs.get(topic) {
+        Some(text) => println!("{}", text),
+        None => println!("'{}' için belge bulunamadı", topic),
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,702 @@
+// src/novac.rs
+// NovaLang için derlenmiş modül dosyası (.novac): yazma ve okuma
+//
+// Düzen (tüm tamsayılar little-endian):
+//   "NOVC" | sürüm u16 | kaynak adı | kaynak damgası (mtime u64, boyut u64)
+//   dışa aktarılanlar: sayı u32, isimler
+//   fonksiyonlar: sayı u32, her biri için
+//     ad | parametre u8 | upvalue'lar | sabitler | komutlar | satır tablosu
+// String'ler u32 uzunluk ve UTF-8 baytlarıdır. Satır tablosunda her komut için
+// (satır u32, sütun u32) bulunur.
+
+use crate::bytecode::{Chunk, Constant, Function, Module, Op, UpvalueDesc};
+use crate::span::{SourceMap, Span};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+const MAGIC: &[u8; 4] = b"NOVC";
+pub const VERSION: u16 = 4;
+
+/// Kaynak dosyanın değişiklik zamanı (saniye) ve boyutu; `.novac` güncel mi diye bakılır.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stamp {
+    pub mtime: u64,
+    pub size: u64,
+}
+
+impl Stamp {
+    pub fn of(path: &Path) -> Option<Stamp> {
+        let meta = std::fs::metadata(path).ok()?;
+        let mtime = meta.modified().ok()?.duration_since(UNIX_EPOCH).ok()?.as_secs();
+        Some(Stamp { mtime, size: meta.len() })
+    }
+}
+
+pub struct Header {
+    pub source_name: String,
+    pub stamp: Stamp,
+}
+
+// --- Yazma ---
+
+/// Modülü `.novac` baytlarına çevirir; komut aralıkları `sources` ile satır/sütuna dönüştürülür.
+pub fn encode(module: &Module, sources: &SourceMap, source_name: &str, stamp: Stamp) -> Vec<u8> {
+    let mut w = Writer(Vec::new());
+    w.0.extend_from_slice(MAGIC);
+    w.u16(VERSION);
+    w.string(source_name);
+    w.u64(stamp.mtime);
+    w.u64(stamp.size);
+    w.u32(module.exports.len() as u32);
+    for name in &module.exports {
+        w.string(name);
+    }
+    w.u32(module.functions.len() as u32);
+    for function in &module.functions {
+        w.function(function, sources);
+    }
+    w.0
+}
+
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn u8(&mut self, v: u8) {
+        self.0.push(v);
+    }
+
+    fn u16(&mut self, v: u16) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn u32(&mut self, v: u32) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn u64(&mut self, v: u64) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn string(&mut self, s: &str) {
+        self.u32(s.len() as u32);
+        self.0.extend_from_slice(s.as_bytes());
+    }
+
+    fn function(&mut self, function: &Function, sources: &SourceMap) {
+        self.string(&function.name);
+        self.u8(function.arity);
+        self.u32(function.upvalues.len() as u32);
+        for upvalue in &function.upvalues {
+            self.u8(upvalue.is_local as u8);
+            self.u16(upvalue.index);
+        }
+        let chunk = &function.chunk;
+        self.u32(chunk.constants.len() as u32);
+        for constant in &chunk.constants {
+            self.constant(constant);
+        }
+        self.u32(chunk.code.len() as u32);
+        for op in &chunk.code {
+            self.op(*op);
+        }
+        for (i, span) in chunk.spans.iter().enumerate() {
+            let (line, col) = match chunk.lines.get(i) {
+                Some(&(line, col)) => (line, col),
+                None => {
+                    let (line, col) = sources.line_col(span.file_id, span.start);
+                    (line as u32, col as u32)
+                }
+            };
+            self.u32(line);
+            self.u32(col);
+        }
+    }
+
+    fn constant(&mut self, constant: &Constant) {
+        match constant {
+            Constant::Int(i) => {
+                self.u8(0);
+                self.u64(*i as u64);
+            }
+            Constant::Float(f) => {
+                self.u8(1);
+                self.u64(f.to_bits());
+            }
+            Constant::String(s) => {
+                self.u8(2);
+                self.string(s);
+            }
+            Constant::Function(index) => {
+                self.u8(3);
+                self.u16(*index);
+            }
+        }
+    }
+
+    fn op(&mut self, op: Op) {
+        self.u8(opcode(op));
+        match op {
+            Op::Constant(a)
+            | Op::GetLocal(a)
+            | Op::SetLocal(a)
+            | Op::GetUpvalue(a)
+            | Op::SetUpvalue(a)
+            | Op::DefineGlobal(a)
+            | Op::GetGlobal(a)
+            | Op::SetGlobal(a)
+            | Op::GetField(a)
+            | Op::SetField(a)
+            | Op::Closure(a)
+            | Op::BuildList(a)
+            | Op::BuildMap(a)
+            | Op::Interpolate(a)
+            | Op::Class(a)
+            | Op::Inherit(a)
+            | Op::Method(a)
+            | Op::Field(a)
+            | Op::Import(a) => self.u16(a),
+            Op::Jump(target)
+            | Op::JumpIfFalse(target)
+            | Op::IterNext(target)
+            | Op::IterNextPair(target)
+            | Op::PushHandler(target) => self.u32(target),
+            Op::Call(argc) => self.u8(argc),
+            Op::CallGlobal(a, argc) | Op::Invoke(a, argc) | Op::SuperInvoke(a, argc) => {
+                self.u16(a);
+                self.u8(argc);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Komut numaraları dosya biçiminin parçasıdır; değiştirmek `VERSION`'ı artırmayı gerektirir.
+fn opcode(op: Op) -> u8 {
+    match op {
+        Op::Constant(_) => 0,
+        Op::Null => 1,
+        Op::True => 2,
+        Op::False => 3,
+        Op::Pop => 4,
+        Op::Dup => 5,
+        Op::GetLocal(_) => 6,
+        Op::SetLocal(_) => 7,
+        Op::GetUpvalue(_) => 8,
+        Op::SetUpvalue(_) => 9,
+        Op::CloseUpvalue => 10,
+        Op::DefineGlobal(_) => 11,
+        Op::GetGlobal(_) => 12,
+        Op::SetGlobal(_) => 13,
+        Op::GetField(_) => 14,
+        Op::SetField(_) => 15,
+        Op::GetIndex => 16,
+        Op::SetIndex => 17,
+        Op::Add => 18,
+        Op::Subtract => 19,
+        Op::Multiply => 20,
+        Op::Divide => 21,
+        Op::Modulo => 22,
+        Op::Equal => 23,
+        Op::NotEqual => 24,
+        Op::Less => 25,
+        Op::Greater => 26,
+        Op::LessEqual => 27,
+        Op::GreaterEqual => 28,
+        Op::Negate => 29,
+        Op::Not => 30,
+        Op::Jump(_) => 31,
+        Op::JumpIfFalse(_) => 32,
+        Op::Call(_) => 33,
+        Op::CallGlobal(..) => 34,
+        Op::Invoke(..) => 35,
+        Op::SuperInvoke(..) => 36,
+        Op::Closure(_) => 37,
+        Op::Return => 38,
+        Op::BuildList(_) => 39,
+        Op::BuildMap(_) => 40,
+        Op::Interpolate(_) => 41,
+        Op::Class(_) => 42,
+        Op::Inherit(_) => 43,
+        Op::Method(_) => 44,
+        Op::Field(_) => 45,
+        Op::Import(_) => 46,
+        Op::PushHandler(_) => 47,
+        Op::PopHandler => 48,
+        Op::Iter => 49,
+        Op::IterNext(_) => 50,
+        Op::IterNextPair(_) => 51,
+        Op::Range => 52,
+        Op::RangeInclusive => 53,
+        Op::Throw => 54,
+    }
+}
+
+// --- Okuma ---
+
+/// `.novac` dosyası var ve yanındaki kaynak ondan sonra değişmemişse doğrudur.
+/// Kaynak hiç yoksa derlenmiş dosya tek başına kullanılabilir.
+pub fn is_fresh(compiled: &Path, source: &Path) -> bool {
+    let header = match std::fs::read(compiled).map_err(|e| e.to_string()).and_then(|bytes| read_header(&bytes)) {
+        Ok(header) => header,
+        Err(_) => return false,
+    };
+    match Stamp::of(source) {
+        Some(stamp) => stamp == header.stamp,
+        None => true,
+    }
+}
+
+/// `.novac` dosyasını okuyup modülü kurar. Kaynak değişmeden yanında duruyorsa
+/// hata gösteriminde satır metni için o da `sources`'a eklenir.
+pub fn load(path: &Path, sources: &mut SourceMap) -> Result<Module, String> {
+    let bytes = std::fs::read(path).map_err(|_| "Dosya okunamadı".to_string())?;
+    let header = read_header(&bytes)?;
+    let source = path.with_extension("nova");
+    let text = match Stamp::of(&source) {
+        Some(stamp) if stamp == header.stamp => std::fs::read_to_string(&source).unwrap_or_default(),
+        _ => String::new(),
+    };
+    let file_id = sources.add(&header.source_name, &text);
+    decode(&bytes, file_id).map(|(_, module)| module)
+}
+
+/// Yalnızca başlığı okur; kaynağın değişip değişmediğine bakmak için yeterlidir.
+pub fn read_header(bytes: &[u8]) -> Result<Header, String> {
+    Reader { bytes, pos: 0 }.header()
+}
+
+/// `.novac` baytlarından modülü kurar. Komut aralıkları `file_id` dosyasına bağlanır;
+/// hata konumları satır tablosundan gelir.
+pub fn decode(bytes: &[u8], file_id: usize) -> Result<(Header, Module), String> {
+    let mut r = Reader { bytes, pos: 0 };
+    let header = r.header()?;
+    let mut module = Module::default();
+    for _ in 0..r.u32()? {
+        module.exports.push(r.string()?);
+    }
+    for _ in 0..r.u32()? {
+        module.functions.push(r.function(file_id)?);
+    }
+    if r.pos != bytes.len() {
+        return Err("Dosya sonunda fazladan veri var".to_string());
+    }
+    // Fonksiyon sabitleri tablodaki var olan bir fonksiyonu göstermeli
+    let count = module.functions.len();
+    let dangling = module.functions.iter().flat_map(|f| &f.chunk.constants).any(|c| matches!(c, Constant::Function(i) if *i as usize >= count));
+    if dangling {
+        return Err("Geçersiz fonksiyon sabiti".to_string());
+    }
+    if module.functions.is_empty() {
+        return Err("Dosyada betik fonksiyonu yok".to_string());
+    }
+    for function in &module.functions {
+        check_stack(&module, function)?;
+    }
+    Ok((header, module))
+}
+
+/// Kodu yığın yüksekliğini izleyerek gezer: yerel yuvalar ve upvalue sıraları sınır içinde
+/// kalmalı, hiçbir komut yığında olmayan değeri almamalı, birleşen yollar aynı yükseklikte
+/// gelmeli ve kod dönüşsüz bitmemeli. VM bu varsayımları denetlemeden kullanır.
+fn check_stack(module: &Module, function: &Function) -> Result<(), String> {
+    let invalid = || format!("'{}' fonksiyonunda geçersiz yığın kullanımı", function.name);
+    let code = &function.chunk.code;
+    let mut heights: Vec<Option<usize>> = vec![None; code.len()];
+    // 0. yuvada çağrılan değer, ardından parametreler durur
+    let mut work = vec![(0, function.arity as usize + 1)];
+    while let Some((ip, height)) = work.pop() {
+        let op = *code.get(ip).ok_or_else(invalid)?;
+        match heights[ip] {
+            Some(seen) if seen == height => continue,
+            Some(_) => return Err(invalid()),
+            None => heights[ip] = Some(height),
+        }
+        let operands_ok = match op {
+            Op::GetLocal(slot) | Op::SetLocal(slot) => (slot as usize) < height,
+            Op::GetUpvalue(index) | Op::SetUpvalue(index) => (index as usize) < function.upvalues.len(),
+            Op::Closure(index) => match function.chunk.constants[index as usize] {
+                Constant::Function(f) => module.functions[f as usize].upvalues.iter().all(|desc| {
+                    let limit = if desc.is_local { height } else { function.upvalues.len() };
+                    (desc.index as usize) < limit
+                }),
+                _ => false,
+            },
+            _ => true,
+        };
+        let (needs, pushes) = stack_effect(op);
+        if !operands_ok || height < needs {
+            return Err(invalid());
+        }
+        let next = height - needs + pushes;
+        match op {
+            Op::Return | Op::Throw => {}
+            Op::Jump(target) => work.push((target as usize, next)),
+            Op::JumpIfFalse(target) => work.extend([(target as usize, next), (ip + 1, next)]),
+            // Yineleyici bitince yığına bir şey koymadan atlar
+            Op::IterNext(target) | Op::IterNextPair(target) => work.extend([(target as usize, height), (ip + 1, next)]),
+            // Yakalanan hata, kurulduğu andaki yığının üstüne konur
+            Op::PushHandler(target) => work.extend([(target as usize, height + 1), (ip + 1, next)]),
+            _ => work.push((ip + 1, next)),
+        }
+    }
+    Ok(())
+}
+
+/// Komutun yığından aldığı ve yığına koyduğu değer sayısı. Değeri yerinde bırakan komutlar
+/// (Dup, Set*, Inherit, IterNext) aldıklarını geri koymuş sayılır.
+fn stack_effect(op: Op) -> (usize, usize) {
+    match op {
+        Op::Constant(_)
+        | Op::Null
+        | Op::True
+        | Op::False
+        | Op::GetLocal(_)
+        | Op::GetUpvalue(_)
+        | Op::GetGlobal(_)
+        | Op::Closure(_)
+        | Op::Class(_) => (0, 1),
+        Op::Pop | Op::CloseUpvalue | Op::DefineGlobal(_) | Op::JumpIfFalse(_) | Op::Return | Op::Throw => (1, 0),
+        Op::Dup => (1, 2),
+        Op::SetLocal(_)
+        | Op::SetUpvalue(_)
+        | Op::SetGlobal(_)
+        | Op::GetField(_)
+        | Op::Negate
+        | Op::Not
+        | Op::Iter => (1, 1),
+        Op::Inherit(_) => (2, 2),
+        Op::IterNext(_) => (1, 2),
+        Op::IterNextPair(_) => (1, 3),
+        Op::SetField(_)
+        | Op::GetIndex
+        | Op::Add
+        | Op::Subtract
+        | Op::Multiply
+        | Op::Divide
+        | Op::Modulo
+        | Op::Equal
+        | Op::NotEqual
+        | Op::Less
+        | Op::Greater
+        | Op::LessEqual
+        | Op::GreaterEqual
+        | Op::Range
+        | Op::RangeInclusive
+        | Op::Method(_)
+        | Op::Field(_) => (2, 1),
+        Op::SetIndex => (3, 1),
+        Op::Jump(_) | Op::Import(_) | Op::PushHandler(_) | Op::PopHandler => (0, 0),
+        Op::Call(argc) | Op::Invoke(_, argc) => (argc as usize + 1, 1),
+        Op::SuperInvoke(_, argc) => (argc as usize + 2, 1),
+        Op::CallGlobal(_, argc) => (argc as usize, 1),
+        Op::BuildList(count) | Op::Interpolate(count) => (count as usize, 1),
+        Op::BuildMap(count) => (2 * count as usize, 1),
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn take(&mut self, n: usize) -> Result<&[u8], String> {
+        let end = self.pos.checked_add(n).filter(|&end| end <= self.bytes.len());
+        let end = end.ok_or_else(|| "Dosya beklenenden erken bitti".to_string())?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let len = self.u32()? as usize;
+        let bytes = self.take(len)?.to_vec();
+        String::from_utf8(bytes).map_err(|_| "Geçersiz UTF-8 string".to_string())
+    }
+
+    fn header(&mut self) -> Result<Header, String> {
+        if self.take(4).ok() != Some(MAGIC.as_slice()) {
+            return Err("Geçerli bir .novac dosyası değil".to_string());
+        }
+        let version = self.u16()?;
+        if version != VERSION {
+            return Err(format!("Desteklenmeyen .novac sürümü: {} (beklenen {})", version, VERSION));
+        }
+        let source_name = self.string()?;
+        let stamp = Stamp { mtime: self.u64()?, size: self.u64()? };
+        Ok(Header { source_name, stamp })
+    }
+
+    fn function(&mut self, file_id: usize) -> Result<Function, String> {
+        let name = self.string()?;
+        let arity = self.u8()?;
+        let mut upvalues = Vec::new();
+        for _ in 0..self.u32()? {
+            upvalues.push(UpvalueDesc { is_local: self.u8()? != 0, index: self.u16()? });
+        }
+        let mut chunk = Chunk::default();
+        for _ in 0..self.u32()? {
+            let constant = self.constant()?;
+            chunk.constants.push(constant);
+        }
+        let code_len = self.u32()?;
+        for _ in 0..code_len {
+            let op = self.op()?;
+            chunk.code.push(op);
+        }
+        for _ in 0..code_len {
+            chunk.lines.push((self.u32()?, self.u32()?));
+            chunk.spans.push(Span::new(file_id, 0, 0));
+        }
+        self.check_operands(&chunk)?;
+        Ok(Function { name, arity, chunk, upvalues })
+    }
+
+    fn constant(&mut self) -> Result<Constant, String> {
+        Ok(match self.u8()? {
+            0 => Constant::Int(self.u64()? as i64),
+            1 => Constant::Float(f64::from_bits(self.u64()?)),
+            2 => Constant::String(self.string()?),
+            3 => Constant::Function(self.u16()?),
+            tag => return Err(format!("Bilinmeyen sabit türü: {}", tag)),
+        })
+    }
+
+    fn op(&mut self) -> Result<Op, String> {
+        Ok(match self.u8()? {
+            0 => Op::Constant(self.u16()?),
+            1 => Op::Null,
+            2 => Op::True,
+            3 => Op::False,
+            4 => Op::Pop,
+            5 => Op::Dup,
+            6 => Op::GetLocal(self.u16()?),
+            7 => Op::SetLocal(self.u16()?),
+            8 => Op::GetUpvalue(self.u16()?),
+            9 => Op::SetUpvalue(self.u16()?),
+            10 => Op::CloseUpvalue,
+            11 => Op::DefineGlobal(self.u16()?),
+            12 => Op::GetGlobal(self.u16()?),
+            13 => Op::SetGlobal(self.u16()?),
+            14 => Op::GetField(self.u16()?),
+            15 => Op::SetField(self.u16()?),
+            16 => Op::GetIndex,
+            17 => Op::SetIndex,
+            18 => Op::Add,
+            19 => Op::Subtract,
+            20 => Op::Multiply,
+            21 => Op::Divide,
+            22 => Op::Modulo,
+            23 => Op::Equal,
+            24 => Op::NotEqual,
+            25 => Op::Less,
+            26 => Op::Greater,
+            27 => Op::LessEqual,
+            28 => Op::GreaterEqual,
+            29 => Op::Negate,
+            30 => Op::Not,
+            31 => Op::Jump(self.u32()?),
+            32 => Op::JumpIfFalse(self.u32()?),
+            33 => Op::Call(self.u8()?),
+            34 => Op::CallGlobal(self.u16()?, self.u8()?),
+            35 => Op::Invoke(self.u16()?, self.u8()?),
+            36 => Op::SuperInvoke(self.u16()?, self.u8()?),
+            37 => Op::Closure(self.u16()?),
+            38 => Op::Return,
+            39 => Op::BuildList(self.u16()?),
+            40 => Op::BuildMap(self.u16()?),
+            41 => Op::Interpolate(self.u16()?),
+            42 => Op::Class(self.u16()?),
+            43 => Op::Inherit(self.u16()?),
+            44 => Op::Method(self.u16()?),
+            45 => Op::Field(self.u16()?),
+            46 => Op::Import(self.u16()?),
+            47 => Op::PushHandler(self.u32()?),
+            48 => Op::PopHandler,
+            49 => Op::Iter,
+            50 => Op::IterNext(self.u32()?),
+            51 => Op::IterNextPair(self.u32()?),
+            52 => Op::Range,
+            53 => Op::RangeInclusive,
+            54 => Op::Throw,
+            code => return Err(format!("Bilinmeyen komut: {}", code)),
+        })
+    }
+
+    /// Sabit sıraları, sabit türleri ve atlama hedefleri geçerli olmalı; VM bunları denetlemez.
+    fn check_operands(&self, chunk: &Chunk) -> Result<(), String> {
+        let name = |i: u16| matches!(chunk.constants.get(i as usize), Some(Constant::String(_)));
+        let ok = chunk.code.iter().all(|op| match *op {
+            Op::Constant(i) => matches!(chunk.constants.get(i as usize), Some(c) if !matches!(c, Constant::Function(_))),
+            Op::Closure(i) => matches!(chunk.constants.get(i as usize), Some(Constant::Function(_))),
+            Op::DefineGlobal(i)
+            | Op::GetGlobal(i)
+            | Op::SetGlobal(i)
+            | Op::GetField(i)
+            | Op::SetField(i)
+            | Op::Class(i)
+            | Op::Inherit(i)
+            | Op::Method(i)
+            | Op::Field(i)
+            | Op::Import(i)
+            | Op::CallGlobal(i, _)
+            | Op::Invoke(i, _)
+            | Op::SuperInvoke(i, _) => name(i),
+            Op::Jump(target)
+            | Op::JumpIfFalse(target)
+            | Op::IterNext(target)
+            | Op::IterNextPair(target)
+            | Op::PushHandler(target) => (target as usize) < chunk.code.len(),
+            _ => true,
+        });
+        if ok {
+            Ok(())
+        } else {
+            Err("Geçersiz komut işleneni".to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::Compiler;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+    use crate::semantic::Resolver;
+    use crate::stackvm::StackVM;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    const PROGRAM: &str = r#"
+class Counter {
+    let count = 0;
+    func add(n) { this.count = this.count + n; return this; }
+}
+func make(step) {
+    let total = 0;
+    func next() { total = total + step; return total; }
+    return next;
+}
+let next = make(2);
+let c = Counter();
+for i in [1, 2, 3] { c.add(i); }
+let m = {"a": next(), "b": next()};
+try { throw "hata"; } catch (e) { print("yakalandı ${e.message}"); }
+print(c.count, m["b"], 1 < 2 && !false);
+"#;
+
+    fn compile(code: &str) -> (SourceMap, Module) {
+        let mut sources = SourceMap::new();
+        let file_id = sources.add("test.nova", code);
+        let tokens = Lexer::new(code).with_file(file_id).tokenize().unwrap();
+        let mut program = Parser::new(tokens).parse_program().unwrap();
+        Resolver::new(StackVM::new().known_globals()).resolve(&mut program).unwrap();
+        (sources, Compiler::new().compile(&program).unwrap())
+    }
+
+    fn encoded(module: &Module, sources: &SourceMap) -> Vec<u8> {
+        encode(module, sources, "test.nova", Stamp::default())
+    }
+
+    /// Fonksiyonun kodunu değiştirir; satır tablosu yeni uzunluğa uydurulur.
+    fn replace_code(module: &mut Module, function: usize, code: Vec<Op>) {
+        let chunk = &mut module.functions[function].chunk;
+        chunk.lines = vec![(1, 1); code.len()];
+        chunk.spans = vec![Span::default(); code.len()];
+        chunk.code = code;
+    }
+
+    #[test]
+    fn round_trip_keeps_module() {
+        let (sources, module) = compile(PROGRAM);
+        let bytes = encoded(&module, &sources);
+        let (header, decoded) = decode(&bytes, 0).unwrap();
+        assert_eq!(header.source_name, "test.nova");
+        assert_eq!(decoded.functions.len(), module.functions.len());
+        for (a, b) in module.functions.iter().zip(&decoded.functions) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.arity, b.arity);
+            assert_eq!(a.upvalues, b.upvalues);
+            assert_eq!(a.chunk.code, b.chunk.code);
+            assert_eq!(a.chunk.constants, b.chunk.constants);
+        }
+        assert_eq!(encoded(&decoded, &sources), bytes);
+    }
+
+    #[test]
+    fn rejects_bad_operands() {
+        let (sources, module) = compile(PROGRAM);
+        let cases: [fn(&mut Module); 7] = [
+            // Yerel yuva yığının dışında
+            |m| replace_code(m, 0, vec![Op::GetLocal(9), Op::Return]),
+            // Betiğin upvalue'su yok
+            |m| replace_code(m, 0, vec![Op::GetUpvalue(0), Op::Return]),
+            // Yığında olmayan değerler alınıyor
+            |m| replace_code(m, 0, vec![Op::Pop, Op::Add, Op::Return]),
+            |m| replace_code(m, 0, vec![Op::Null, Op::BuildList(5), Op::Return]),
+            // Kod dönüşsüz bitiyor
+            |m| replace_code(m, 0, vec![Op::Null]),
+            // Birleşen yollar farklı yükseklikte
+            |m| replace_code(m, 0, vec![Op::True, Op::JumpIfFalse(3), Op::Null, Op::Null, Op::Return]),
+            // Kapanışın yakaladığı yerel yuva yok
+            |m| {
+                let inner = m.functions.iter().position(|f| f.name == "next").unwrap();
+                m.functions[inner].upvalues[0] = UpvalueDesc { is_local: true, index: 40 };
+            },
+        ];
+        for (i, corrupt) in cases.iter().enumerate() {
+            let mut module = module.clone();
+            corrupt(&mut module);
+            assert!(decode(&encoded(&module, &sources), 0).is_err(), "bozuk modül {} kabul edildi", i);
+        }
+    }
+
+    #[test]
+    fn corrupted_bytes_never_panic() {
+        let (sources, module) = compile(PROGRAM);
+        let bytes = encoded(&module, &sources);
+        let mut seed: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut random = move |limit: usize| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) as usize % limit
+        };
+        for _ in 0..200 {
+            let mut corrupted = bytes.clone();
+            for _ in 0..5 {
+                let at = random(corrupted.len());
+                corrupted[at] = random(256) as u8;
+            }
+            // Çözülen modül çalıştırılır; bozuk atlamalar sonsuz döngü kurabileceği için
+            // süresi dolan çalıştırma başarılı sayılır, panik ise test hatasıdır
+            let (done, finished) = mpsc::channel();
+            thread::spawn(move || {
+                if let Ok((_, module)) = decode(&corrupted, 0) {
+                    let _ = StackVM::new().run(module);
+                }
+                let _ = done.send(());
+            });
+            assert!(
+                !matches!(finished.recv_timeout(Duration::from_secs(2)), Err(mpsc::RecvTimeoutError::Disconnected)),
+                "bozuk dosya panik oluşturdu"
+            );
+        }
+    }
+}
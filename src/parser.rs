@@ -1,221 +1,997 @@
-// src/parser.rs
-// NovaLang için kapsamlı ve modüler parser
-
-use crate::token::{Token, TokenKind};
-use crate::ast::{Expr, Stmt, Program};
-use crate::error::{NovaError, NovaErrorKind};
-
-pub struct Parser {
-    tokens: Vec<Token>,
-    pos: usize,
-}
-
-impl Parser {
-    pub fn new(tokens: Vec<Token>) -> Self {
-        Parser { tokens, pos: 0 }
-    }
-
-    fn peek(&self) -> Option<&Token> {
-        self.tokens.get(self.pos)
-    }
-
-    fn advance(&mut self) -> Option<&Token> {
-        let tok = self.tokens.get(self.pos);
-        if tok.is_some() {
-            self.pos += 1;
-        }
-        tok
-    }
-
-    pub fn parse_program(&mut self) -> Result<Program, NovaError> {
-        let mut body = Vec::new();
-        while let Some(token) = self.peek() {
-            if let TokenKind::Eof = token.kind {
-                break;
-            }
-            let stmt = self.parse_stmt()?;
-            body.push(stmt);
-        }
-        Ok(Program { body })
-    }
-
-    fn parse_stmt(&mut self) -> Result<Stmt, NovaError> {
-        // Burada let, func, if, while, return, expr, block ayrımı yapılacak
-        Err(NovaError::new(NovaErrorKind::ParseError, "Henüz uygulanmadı", 0, 0, None))
-    }
-
-    fn parse_list_literal(&mut self) -> Result<Expr, NovaError> {
-        let start = self.advance().unwrap(); // '['
-        let mut items = Vec::new();
-        let mut first = true;
-        while let Some(tok) = self.peek() {
-            if let TokenKind::RBracket = tok.kind {
-                self.advance();
-                break;
-            }
-            if !first {
-                self.expect(TokenKind::Comma)?;
-            } else {
-                first = false;
-            }
-            let expr = self.parse_expr()?;
-            items.push(expr);
-        }
-        Ok(Expr::List(items, start.line, start.col))
-    }
-
-    fn parse_map_literal(&mut self) -> Result<Expr, NovaError> {
-        let start = self.advance().unwrap(); // '{'
-        let mut pairs = Vec::new();
-        let mut first = true;
-        while let Some(tok) = self.peek() {
-            if let TokenKind::RBrace = tok.kind {
-                self.advance();
-                break;
-            }
-            if !first {
-                self.expect(TokenKind::Comma)?;
-            } else {
-                first = false;
-            }
-            let key = self.parse_expr()?;
-            self.expect(TokenKind::Colon)?;
-            let value = self.parse_expr()?;
-            pairs.push((key, value));
-        }
-        Ok(Expr::Map(pairs, start.line, start.col))
-    }
-
-    fn parse_index_expr(&mut self, collection: Expr) -> Result<Expr, NovaError> {
-        let lbracket = self.advance().unwrap(); // '['
-        let index = self.parse_expr()?;
-        self.expect(TokenKind::RBracket)?;
-        Ok(Expr::Index {
-            collection: Box::new(collection),
-            index: Box::new(index),
-            line: lbracket.line,
-            col: lbracket.col,
-        })
-    }
-
-    fn expect(&mut self, kind: TokenKind) -> Result<(), NovaError> {
-        if let Some(tok) = self.peek() {
-            if tok.kind == kind {
-                self.advance();
-                Ok(())
-            } else {
-                Err(NovaError::new(NovaErrorKind::ParseError, "Beklenen token bulunamadı", tok.line, tok.col, None))
-            }
-        } else {
-            Err(NovaError::new(NovaErrorKind::ParseError, "Beklenen token bulunamadı", 0, 0, None))
-        }
-    }
-
-    fn parse_try_catch(&mut self) -> Result<Stmt, NovaError> {
-        let try_tok = self.advance().unwrap(); // 'try'
-        self.expect(TokenKind::LBrace)?;
-        let try_block = self.parse_block()?;
-        let catch_tok = self.advance().unwrap(); // 'catch'
-        self.expect(TokenKind::LParen)?;
-        let err_name = if let Some(Token { kind: TokenKind::Identifier(name), .. }) = self.advance() {
-            name
-        } else {
-            return Err(NovaError::new(NovaErrorKind::ParseError, "catch bloğunda hata değişkeni bekleniyor", catch_tok.line, catch_tok.col, None));
-        };
-        self.expect(TokenKind::RParen)?;
-        self.expect(TokenKind::LBrace)?;
-        let catch_block = self.parse_block()?;
-        Ok(Stmt::TryCatch {
-            try_block,
-            err_name,
-            catch_block,
-            line: try_tok.line,
-            col: try_tok.col,
-        })
-    }
-
-    fn parse_import(&mut self) -> Result<Stmt, NovaError> {
-        let import_tok = self.advance().unwrap(); // 'import'
-        let name = if let Some(Token { kind: TokenKind::Identifier(name), .. }) = self.advance() {
-            name
-        } else {
-            return Err(NovaError::new(NovaErrorKind::ParseError, "import sonrası modül adı bekleniyor", import_tok.line, import_tok.col, None));
-        };
-        self.expect(TokenKind::Semicolon)?;
-        Ok(Stmt::Import { name, line: import_tok.line, col: import_tok.col })
-    }
-
-    fn parse_lambda(&mut self) -> Result<Expr, NovaError> {
-        let lparen = self.expect(TokenKind::LParen)?;
-        let mut params = Vec::new();
-        let mut first = true;
-        while let Some(tok) = self.peek() {
-            if let TokenKind::RParen = tok.kind {
-                self.advance();
-                break;
-            }
-            if !first {
-                self.expect(TokenKind::Comma)?;
-            } else {
-                first = false;
-            }
-            if let Some(Token { kind: TokenKind::Identifier(name), .. }) = self.advance() {
-                params.push(name);
-            } else {
-                return Err(NovaError::new(NovaErrorKind::ParseError, "Lambda parametresi bekleniyor", 0, 0, None));
-            }
-        }
-        self.expect(TokenKind::Arrow)?;
-        let body = Box::new(self.parse_expr()?);
-        Ok(Expr::Lambda {
-            params,
-            body,
-            line: lparen.line,
-            col: lparen.col,
-        })
-    }
-
-    fn parse_class(&mut self) -> Result<Stmt, NovaError> {
-        // class SınıfAdı [extends ÜstSınıf] { ... }
-        // ...
-        Err(NovaError::new(NovaErrorKind::ParseError, "Class parse edilmedi", 0, 0, None))
-    }
-
-    fn parse_export(&mut self) -> Result<Stmt, NovaError> {
-        // export let/fonksiyon/sınıf
-        // ...
-        Err(NovaError::new(NovaErrorKind::ParseError, "Export parse edilmedi", 0, 0, None))
-    }
-
-    fn parse_async_func(&mut self) -> Result<Stmt, NovaError> {
-        // async func ...
-        // ...
-        Err(NovaError::new(NovaErrorKind::ParseError, "Async func parse edilmedi", 0, 0, None))
-    }
-
-    fn parse_await(&mut self) -> Result<Expr, NovaError> {
-        // await expr
-        // ...
-        Err(NovaError::new(NovaErrorKind::ParseError, "Await parse edilmedi", 0, 0, None))
-    }
-
-    fn parse_try_catch_finally(&mut self) -> Result<Stmt, NovaError> {
-        // try { ... } catch (err) { ... } finally { ... }
-        // ...
-        Err(NovaError::new(NovaErrorKind::ParseError, "Try-catch-finally parse edilmedi", 0, 0, None))
-    }
-
-    fn parse_method_call(&mut self, object: Expr) -> Result<Expr, NovaError> {
-        // obj.method(args)
-        // ...
-        Err(NovaError::new(NovaErrorKind::ParseError, "Method call parse edilmedi", 0, 0, None))
-    }
-
-    fn parse_new(&mut self) -> Result<Expr, NovaError> {
-        // new SınıfAdı(args)
-        // ...
-        Err(NovaError::new(NovaErrorKind::ParseError, "New parse edilmedi", 0, 0, None))
-    }
-
-    // Diğer yardımcı parse fonksiyonları buraya eklenecek
-} 
\ No newline at end of file
+// src/parser.rs
+// NovaLang için kapsamlı ve modüler parser
+
+use crate::token::{TemplatePart, Token, TokenKind};
+use crate::ast::{CatchClause, Expr, Stmt, Program};
+use crate::error::{NovaError, NovaErrorKind};
+use crate::span::Span;
+use crate::lexer::KeywordSet;
+
+pub struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    // Hata mesajlarında token'lar dosyanın anahtar kelime diliyle yazılır
+    keywords: KeywordSet,
+    errors: Vec<NovaError>,
+}
+
+impl Parser {
+    pub fn new(tokens: Vec<Token>) -> Self {
+        Parser { tokens: keep_attached_docs(tokens), pos: 0, keywords: KeywordSet::English, errors: Vec::new() }
+    }
+
+    pub fn with_keywords(mut self, keywords: KeywordSet) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Anahtar kelimenin dosyanın dilindeki yazılışı; hata mesajları bununla kurulur.
+    fn keyword(&self, kind: TokenKind) -> String {
+        kind.to_source(self.keywords)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let tok = self.tokens.get(self.pos).cloned();
+        if tok.is_some() {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    /// Son tüketilen token'ın aralığı; düğüm span'lerinin bitişi olarak kullanılır.
+    fn previous_span(&self) -> Span {
+        self.pos.checked_sub(1).and_then(|i| self.tokens.get(i)).map(|t| t.span).unwrap_or_default()
+    }
+
+    /// `start` token'ından son tüketilen token'a kadar uzanan aralık.
+    fn span_from(&self, start: &Token) -> Span {
+        start.span.to(self.previous_span())
+    }
+
+    fn peek_kind(&self, ahead: usize) -> Option<&TokenKind> {
+        self.tokens.get(self.pos + ahead).map(|t| &t.kind)
+    }
+
+    fn check(&self, kind: &TokenKind) -> bool {
+        self.peek().is_some_and(|t| &t.kind == kind)
+    }
+
+    fn match_token(&mut self, kind: &TokenKind) -> bool {
+        if self.check(kind) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Bir sonraki token'ı; token listesi bittiyse son token'ı (`Eof`) döndürür.
+    fn current(&self) -> Token {
+        self.peek().or(self.tokens.last()).cloned().unwrap_or(Token {
+            kind: TokenKind::Eof,
+            line: 0,
+            col: 0,
+            span: Span::default(),
+        })
+    }
+
+    fn expect_identifier(&mut self, message: &str) -> Result<String, NovaError> {
+        let tok = self.current();
+        if let TokenKind::Identifier(name) = tok.kind {
+            self.advance();
+            Ok(name)
+        } else {
+            Err(self.error_at(&tok, message))
+        }
+    }
+
+    /// Basit deyimleri bitiren `;`. Bloğun ya da dosyanın son deyiminde isteğe bağlıdır.
+    fn end_stmt(&mut self) -> Result<(), NovaError> {
+        if self.match_token(&TokenKind::Semicolon) || self.check(&TokenKind::RBrace) || self.check(&TokenKind::Eof) {
+            Ok(())
+        } else {
+            self.expect(TokenKind::Semicolon).map(|_| ())
+        }
+    }
+
+    fn error_at(&self, tok: &Token, message: &str) -> NovaError {
+        NovaError::new(NovaErrorKind::ParseError, message, tok.line, tok.col, None)
+            .with_span(tok.span)
+            .with_token(&tok.kind.to_source(self.keywords))
+    }
+
+    /// Programı ayrıştırır. Hatalı deyimler kaydedilip atlanır, böylece dosyadaki
+    /// tüm sözdizimi hataları tek seferde döndürülür.
+    pub fn parse_program(&mut self) -> Result<Program, Vec<NovaError>> {
+        let mut body = Vec::new();
+        while !self.check(&TokenKind::Eof) && self.peek().is_some() {
+            let errors_before = self.errors.len();
+            if let Some(stmt) = self.parse_stmt_recovering() {
+                body.push(stmt);
+            }
+            // Hatalı bir bloğun kalan `}` karakterleri yeni hata üretmesin
+            if self.errors.len() > errors_before {
+                while self.check(&TokenKind::RBrace) {
+                    self.advance();
+                }
+            }
+        }
+        if self.errors.is_empty() {
+            Ok(Program { body })
+        } else {
+            Err(std::mem::take(&mut self.errors))
+        }
+    }
+
+    /// Bir deyimi ayrıştırır; hata olursa kaydeder ve bir sonraki deyim sınırına atlar.
+    fn parse_stmt_recovering(&mut self) -> Option<Stmt> {
+        let start = self.pos;
+        match self.parse_stmt() {
+            Ok(stmt) => Some(stmt),
+            Err(e) => {
+                self.errors.push(e);
+                let error_pos = self.pos;
+                // İlerleme garantisi: hata ilk token'daysa en az onu atla
+                if self.pos == start {
+                    self.advance();
+                }
+                self.synchronize(error_pos);
+                None
+            }
+        }
+    }
+
+    /// Panik modu: `;` sonrasına, `}` öncesine ya da bir deyim anahtar kelimesine kadar atlar.
+    /// Atlanan `{` ile açılan bloklar kapanışlarıyla birlikte atlanır. Hatanın oluştuğu token
+    /// (`error_pos`) anahtar kelime olsa bile atlanır; `let let = 1;` ikinci bir hata üretmez.
+    fn synchronize(&mut self, error_pos: usize) {
+        let mut depth = 0usize;
+        while let Some(tok) = self.peek() {
+            match tok.kind {
+                TokenKind::Eof => return,
+                TokenKind::LBrace => depth += 1,
+                TokenKind::RBrace if depth == 0 => return,
+                TokenKind::RBrace => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.advance();
+                        return;
+                    }
+                }
+                TokenKind::Semicolon if depth == 0 => {
+                    self.advance();
+                    return;
+                }
+                TokenKind::Let
+                | TokenKind::Func
+                | TokenKind::Async
+                | TokenKind::If
+                | TokenKind::While
+                | TokenKind::For
+                | TokenKind::Return
+                | TokenKind::Throw
+                | TokenKind::Break
+                | TokenKind::Continue
+                | TokenKind::Class
+                | TokenKind::Import
+                | TokenKind::Export
+                | TokenKind::Try if depth == 0 && self.pos > error_pos => return,
+                _ => {}
+            }
+            self.advance();
+        }
+    }
+
+    fn parse_stmt(&mut self) -> Result<Stmt, NovaError> {
+        let doc = self.parse_doc_comment();
+        let stmt = match self.current().kind {
+            TokenKind::Let => self.parse_let(),
+            TokenKind::Func => self.parse_func(),
+            TokenKind::Async => self.parse_async_func(),
+            TokenKind::If => self.parse_if(),
+            TokenKind::While => self.parse_while(),
+            TokenKind::For => self.parse_for(),
+            TokenKind::Return => self.parse_return(),
+            TokenKind::Throw => self.parse_throw(),
+            TokenKind::Break | TokenKind::Continue => self.parse_loop_jump(),
+            TokenKind::LBrace => {
+                let lbrace = self.advance().unwrap();
+                let body = self.parse_block()?;
+                Ok(Stmt::Block(body, self.span_from(&lbrace)))
+            }
+            TokenKind::Class => self.parse_class(),
+            TokenKind::Import => self.parse_import(),
+            TokenKind::Export => self.parse_export(),
+            TokenKind::Try => self.parse_try_catch_finally(),
+            _ => {
+                let expr = self.parse_expr()?;
+                self.end_stmt()?;
+                Ok(Stmt::Expr(expr))
+            }
+        };
+        stmt.map(|s| s.with_doc(doc))
+    }
+
+    fn parse_let(&mut self) -> Result<Stmt, NovaError> {
+        let let_tok = self.advance().unwrap(); // 'let'
+        let name = self.expect_identifier(&format!("{} sonrası değişken adı bekleniyor", self.keyword(TokenKind::Let)))?;
+        // `let x;` değişkeni null ile başlatır
+        let value = if self.match_token(&TokenKind::Assign) {
+            self.parse_expr()?
+        } else {
+            Expr::Null(self.previous_span())
+        };
+        self.end_stmt()?;
+        Ok(Stmt::Let { name, value, span: self.span_from(&let_tok) })
+    }
+
+    fn parse_params(&mut self) -> Result<Vec<String>, NovaError> {
+        self.expect(TokenKind::LParen)?;
+        let mut params = Vec::new();
+        if !self.match_token(&TokenKind::RParen) {
+            loop {
+                params.push(self.expect_identifier("Parametre adı bekleniyor")?);
+                if !self.match_token(&TokenKind::Comma) {
+                    break;
+                }
+            }
+            self.expect(TokenKind::RParen)?;
+        }
+        Ok(params)
+    }
+
+    fn parse_func(&mut self) -> Result<Stmt, NovaError> {
+        let func_tok = self.current();
+        let (name, params, body) = self.parse_func_parts()?;
+        Ok(Stmt::Func { name, params, body, doc: None, span: self.span_from(&func_tok) })
+    }
+
+    /// `func ad(parametreler) { gövde }` kısmını okur; func ve async func ortak kullanır.
+    fn parse_func_parts(&mut self) -> Result<(String, Vec<String>, Vec<Stmt>), NovaError> {
+        self.expect(TokenKind::Func)?;
+        let name = self.expect_identifier(&format!("{} sonrası fonksiyon adı bekleniyor", self.keyword(TokenKind::Func)))?;
+        let params = self.parse_params()?;
+        self.expect(TokenKind::LBrace)?;
+        let body = self.parse_block()?;
+        Ok((name, params, body))
+    }
+
+    fn parse_if(&mut self) -> Result<Stmt, NovaError> {
+        let if_tok = self.advance().unwrap(); // 'if'
+        let cond = self.parse_expr()?;
+        self.expect(TokenKind::LBrace)?;
+        let then_branch = self.parse_block()?;
+        let else_branch = if self.match_token(&TokenKind::Else) {
+            if self.check(&TokenKind::If) {
+                // else if zinciri iç içe bir if deyimi olarak tutulur
+                Some(vec![self.parse_if()?])
+            } else {
+                self.expect(TokenKind::LBrace)?;
+                Some(self.parse_block()?)
+            }
+        } else {
+            None
+        };
+        Ok(Stmt::If { cond, then_branch, else_branch, span: self.span_from(&if_tok) })
+    }
+
+    fn parse_while(&mut self) -> Result<Stmt, NovaError> {
+        let while_tok = self.advance().unwrap(); // 'while'
+        let cond = self.parse_expr()?;
+        self.expect(TokenKind::LBrace)?;
+        let body = self.parse_block()?;
+        Ok(Stmt::While { cond, body, span: self.span_from(&while_tok) })
+    }
+
+    fn parse_for(&mut self) -> Result<Stmt, NovaError> {
+        let for_tok = self.advance().unwrap(); // 'for'
+        let name = self.expect_identifier(&format!("{} sonrası değişken adı bekleniyor", self.keyword(TokenKind::For)))?;
+        let value_name = if self.match_token(&TokenKind::Comma) {
+            Some(self.expect_identifier("',' sonrası ikinci değişken adı bekleniyor")?)
+        } else {
+            None
+        };
+        self.expect(TokenKind::In)?;
+        let iterable = self.parse_expr()?;
+        self.expect(TokenKind::LBrace)?;
+        let body = self.parse_block()?;
+        Ok(Stmt::For { name, value_name, iterable, body, span: self.span_from(&for_tok) })
+    }
+
+    fn parse_return(&mut self) -> Result<Stmt, NovaError> {
+        let return_tok = self.advance().unwrap(); // 'return'
+        let value = if self.check(&TokenKind::Semicolon) || self.check(&TokenKind::RBrace) {
+            Expr::Null(return_tok.span)
+        } else {
+            self.parse_expr()?
+        };
+        self.end_stmt()?;
+        Ok(Stmt::Return(value, self.span_from(&return_tok)))
+    }
+
+    fn parse_throw(&mut self) -> Result<Stmt, NovaError> {
+        let throw_tok = self.advance().unwrap(); // 'throw'
+        let value = self.parse_expr()?;
+        self.end_stmt()?;
+        Ok(Stmt::Throw(value, self.span_from(&throw_tok)))
+    }
+
+    fn parse_loop_jump(&mut self) -> Result<Stmt, NovaError> {
+        let tok = self.advance().unwrap(); // 'break' ya da 'continue'
+        self.end_stmt()?;
+        let span = self.span_from(&tok);
+        Ok(if tok.kind == TokenKind::Break { Stmt::Break(span) } else { Stmt::Continue(span) })
+    }
+
+    /// Art arda gelen `///` satırlarını tek bir doc metninde birleştirir.
+    fn parse_doc_comment(&mut self) -> Option<String> {
+        let mut lines = Vec::new();
+        while let Some(Token { kind: TokenKind::Comment(text), .. }) = self.peek() {
+            lines.push(text.clone());
+            self.advance();
+        }
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        }
+    }
+
+    /// Açılış `{` tüketildikten sonra kapanış `}` dahil bloğun deyimlerini okur.
+    fn parse_block(&mut self) -> Result<Vec<Stmt>, NovaError> {
+        let mut body = Vec::new();
+        loop {
+            let tok = self.current();
+            match tok.kind {
+                TokenKind::RBrace => {
+                    self.advance();
+                    return Ok(body);
+                }
+                TokenKind::Eof => return Err(self.error_at(&tok, "Blok kapatılmadı, '}' bekleniyor")),
+                _ => body.extend(self.parse_stmt_recovering()),
+            }
+        }
+    }
+
+    /// İfade ayrıştırıcının girişi. Öncelik (düşükten yükseğe): atama (sağdan birleşir),
+    /// aralık `..`/`..=`, `||`, `&&`, eşitlik, karşılaştırma, toplama, çarpma, tekli `-`/`!`/`await`,
+    /// ardından çağrı, indeks ve `.` erişimi.
+    fn parse_expr(&mut self) -> Result<Expr, NovaError> {
+        self.parse_assignment()
+    }
+
+    fn parse_assignment(&mut self) -> Result<Expr, NovaError> {
+        let target = self.parse_range()?;
+        if !self.check(&TokenKind::Assign) {
+            return Ok(target);
+        }
+        let assign_tok = self.advance().unwrap();
+        let value = self.parse_assignment()?;
+        let span = target.span().to(value.span());
+        match target {
+            Expr::Identifier(name, _, _) => Ok(Expr::Assign { name, value: Box::new(value), slot: None, span }),
+            Expr::Get { object, name, .. } => Ok(Expr::Set { object, name, value: Box::new(value), span }),
+            Expr::Index { collection, index, .. } => {
+                Ok(Expr::SetIndex { collection, index, value: Box::new(value), span })
+            }
+            _ => Err(self.error_at(&assign_tok, "Geçersiz atama hedefi")),
+        }
+    }
+
+    /// `a..b` zincirlenemez: `0..n..m` bir sözdizimi hatasıdır.
+    fn parse_range(&mut self) -> Result<Expr, NovaError> {
+        let start = self.parse_binary(1)?;
+        let inclusive = match self.current().kind {
+            TokenKind::DotDot => false,
+            TokenKind::DotDotEq => true,
+            _ => return Ok(start),
+        };
+        self.advance();
+        let end = self.parse_binary(1)?;
+        let span = start.span().to(end.span());
+        Ok(Expr::Range { start: Box::new(start), end: Box::new(end), inclusive, span })
+    }
+
+    /// Soldan birleşen ikili operatörler için öncelik tırmanma.
+    fn parse_binary(&mut self, min_prec: u8) -> Result<Expr, NovaError> {
+        let mut left = self.parse_unary()?;
+        while let Some((prec, op)) = binary_op(&self.current().kind) {
+            if prec < min_prec {
+                break;
+            }
+            self.advance();
+            let right = self.parse_binary(prec + 1)?;
+            let span = left.span().to(right.span());
+            left = Expr::BinaryOp { left: Box::new(left), op: op.to_string(), right: Box::new(right), span };
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, NovaError> {
+        let tok = self.current();
+        let op = match tok.kind {
+            TokenKind::Minus => "-",
+            TokenKind::Not => "!",
+            TokenKind::Await => return self.parse_await(),
+            _ => return self.parse_postfix(),
+        };
+        self.advance();
+        let expr = self.parse_unary()?;
+        let span = tok.span.to(expr.span());
+        Ok(Expr::UnaryOp { op: op.to_string(), expr: Box::new(expr), span })
+    }
+
+    fn parse_postfix(&mut self) -> Result<Expr, NovaError> {
+        let mut expr = self.parse_primary()?;
+        loop {
+            expr = match self.current().kind {
+                TokenKind::LParen => {
+                    let args = self.parse_args()?;
+                    let span = expr.span().to(self.previous_span());
+                    Expr::Call { func: Box::new(expr), args, span }
+                }
+                TokenKind::LBracket => self.parse_index_expr(expr)?,
+                TokenKind::Dot => self.parse_method_call(expr)?,
+                _ => return Ok(expr),
+            };
+        }
+    }
+
+    /// `(` ... `)` arasındaki virgülle ayrılmış argümanları okur.
+    fn parse_args(&mut self) -> Result<Vec<Expr>, NovaError> {
+        self.expect(TokenKind::LParen)?;
+        let mut args = Vec::new();
+        if self.match_token(&TokenKind::RParen) {
+            return Ok(args);
+        }
+        loop {
+            args.push(self.parse_expr()?);
+            if !self.match_token(&TokenKind::Comma) {
+                break;
+            }
+        }
+        self.expect(TokenKind::RParen)?;
+        Ok(args)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, NovaError> {
+        let tok = self.current();
+        let expr = match tok.kind {
+            TokenKind::Int(i) => Expr::Int(i, tok.span),
+            TokenKind::Float(f) => Expr::Float(f, tok.span),
+            TokenKind::String(ref s) => Expr::String(s.clone(), tok.span),
+            TokenKind::True => Expr::Bool(true, tok.span),
+            TokenKind::False => Expr::Bool(false, tok.span),
+            TokenKind::Null => Expr::Null(tok.span),
+            TokenKind::Identifier(ref name) => Expr::Identifier(name.clone(), None, tok.span),
+            TokenKind::Template(_) => return self.parse_interpolation(),
+            TokenKind::LBracket => return self.parse_list_literal(),
+            TokenKind::LBrace => return self.parse_map_literal(),
+            TokenKind::New => return self.parse_new(),
+            TokenKind::LParen if self.at_lambda() => return self.parse_lambda(),
+            TokenKind::LParen => {
+                self.advance();
+                let expr = self.parse_expr()?;
+                self.expect(TokenKind::RParen)?;
+                return Ok(expr);
+            }
+            _ => return Err(self.error_at(&tok, "İfade bekleniyor")),
+        };
+        self.advance();
+        Ok(expr)
+    }
+
+    /// `(a, b) =>` biçimini parantezli ifadeden ayırmak için ileriye bakar.
+    fn at_lambda(&self) -> bool {
+        let mut i = self.pos + 1;
+        let kind = |i: usize| self.tokens.get(i).map(|t| &t.kind);
+        if kind(i) != Some(&TokenKind::RParen) {
+            loop {
+                if !matches!(kind(i), Some(TokenKind::Identifier(_))) {
+                    return false;
+                }
+                i += 1;
+                match kind(i) {
+                    Some(TokenKind::Comma) => i += 1,
+                    Some(TokenKind::RParen) => break,
+                    _ => return false,
+                }
+            }
+        }
+        kind(i + 1) == Some(&TokenKind::Arrow)
+    }
+
+    fn parse_list_literal(&mut self) -> Result<Expr, NovaError> {
+        let start = self.advance().unwrap(); // '['
+        let mut items = Vec::new();
+        let mut first = true;
+        while let Some(tok) = self.peek() {
+            if let TokenKind::RBracket = tok.kind {
+                self.advance();
+                break;
+            }
+            if !first {
+                self.expect(TokenKind::Comma)?;
+            } else {
+                first = false;
+            }
+            let expr = self.parse_expr()?;
+            items.push(expr);
+        }
+        Ok(Expr::List(items, self.span_from(&start)))
+    }
+
+    fn parse_map_literal(&mut self) -> Result<Expr, NovaError> {
+        let start = self.advance().unwrap(); // '{'
+        let mut pairs = Vec::new();
+        let mut first = true;
+        while let Some(tok) = self.peek() {
+            if let TokenKind::RBrace = tok.kind {
+                self.advance();
+                break;
+            }
+            if !first {
+                self.expect(TokenKind::Comma)?;
+            } else {
+                first = false;
+            }
+            // `{ad: 1}` içindeki yalın isimler string anahtar olarak okunur
+            let key = match self.current() {
+                Token { kind: TokenKind::Identifier(name), span, .. } if self.peek_kind(1) == Some(&TokenKind::Colon) => {
+                    self.advance();
+                    Expr::String(name, span)
+                }
+                _ => self.parse_expr()?,
+            };
+            self.expect(TokenKind::Colon)?;
+            let value = self.parse_expr()?;
+            pairs.push((key, value));
+        }
+        Ok(Expr::Map(pairs, self.span_from(&start)))
+    }
+
+    fn parse_interpolation(&mut self) -> Result<Expr, NovaError> {
+        let tok = self.advance().unwrap(); // Template
+        let parts = match tok.kind {
+            TokenKind::Template(parts) => parts,
+            _ => return Err(self.error_at(&tok, "İnterpolasyonlu string bekleniyor")),
+        };
+        let mut exprs = Vec::new();
+        for part in parts {
+            match part {
+                TemplatePart::Text(text) => exprs.push(Expr::String(text, tok.span)),
+                TemplatePart::Code(tokens) => {
+                    // Gömülü ifade kendi token listesiyle ayrı bir parser'da ayrıştırılır
+                    let mut sub = Parser::new(tokens).with_keywords(self.keywords);
+                    exprs.push(sub.parse_expr()?);
+                    sub.expect(TokenKind::Eof)?;
+                }
+            }
+        }
+        Ok(Expr::Interpolation { parts: exprs, span: tok.span })
+    }
+
+    fn parse_index_expr(&mut self, collection: Expr) -> Result<Expr, NovaError> {
+        let lbracket = self.advance().unwrap(); // '['
+        let index = self.parse_expr()?;
+        self.expect(TokenKind::RBracket)?;
+        let span = collection.span().to(self.span_from(&lbracket));
+        Ok(Expr::Index {
+            collection: Box::new(collection),
+            index: Box::new(index),
+            span,
+        })
+    }
+
+    fn expect(&mut self, kind: TokenKind) -> Result<Token, NovaError> {
+        let tok = self.current();
+        if tok.kind == kind {
+            self.advance();
+            return Ok(tok);
+        }
+        let message = format!(
+            "Beklenen token bulunamadı: '{}' bekleniyor, '{}' bulundu",
+            kind.to_source(self.keywords),
+            tok.kind.to_source(self.keywords)
+        );
+        Err(self.error_at(&tok, &message))
+    }
+
+    fn parse_catch(&mut self) -> Result<CatchClause, NovaError> {
+        let catch_tok = self.advance().unwrap(); // 'catch'
+        self.expect(TokenKind::LParen)?;
+        let name = if let Some(Token { kind: TokenKind::Identifier(name), .. }) = self.advance() {
+            name
+        } else {
+            let message = format!("{} bloğunda hata değişkeni bekleniyor", self.keyword(TokenKind::Catch));
+            return Err(self.error_at(&catch_tok, &message));
+        };
+        let kind = if self.match_token(&TokenKind::Colon) {
+            Some(self.expect_identifier("':' sonrası hata türü bekleniyor")?)
+        } else {
+            None
+        };
+        self.expect(TokenKind::RParen)?;
+        self.expect(TokenKind::LBrace)?;
+        let body = self.parse_block()?;
+        Ok(CatchClause { name, kind, body, span: self.span_from(&catch_tok) })
+    }
+
+    fn parse_import(&mut self) -> Result<Stmt, NovaError> {
+        let import_tok = self.advance().unwrap(); // 'import'
+        let name = if let Some(Token { kind: TokenKind::Identifier(name), .. }) = self.advance() {
+            name
+        } else {
+            let message = format!("{} sonrası modül adı bekleniyor", self.keyword(TokenKind::Import));
+            return Err(self.error_at(&import_tok, &message));
+        };
+        self.expect(TokenKind::Semicolon)?;
+        Ok(Stmt::Import { name, span: self.span_from(&import_tok) })
+    }
+
+    fn parse_lambda(&mut self) -> Result<Expr, NovaError> {
+        let lparen = self.expect(TokenKind::LParen)?;
+        let mut params = Vec::new();
+        let mut first = true;
+        while let Some(tok) = self.peek() {
+            if let TokenKind::RParen = tok.kind {
+                self.advance();
+                break;
+            }
+            if !first {
+                self.expect(TokenKind::Comma)?;
+            } else {
+                first = false;
+            }
+            params.push(self.expect_identifier("Lambda parametresi bekleniyor")?);
+        }
+        self.expect(TokenKind::Arrow)?;
+        let body = Box::new(self.parse_expr()?);
+        Ok(Expr::Lambda {
+            params,
+            body,
+            span: self.span_from(&lparen),
+        })
+    }
+
+    /// `class Ad [extends|: Üst] { let alan [= ifade]; func metot(...) {...} }`
+    fn parse_class(&mut self) -> Result<Stmt, NovaError> {
+        let class_tok = self.advance().unwrap(); // 'class'
+        let name = self.expect_identifier(&format!("{} sonrası sınıf adı bekleniyor", self.keyword(TokenKind::Class)))?;
+        let base = if self.match_token(&TokenKind::Extends) || self.match_token(&TokenKind::Colon) {
+            Some(self.expect_identifier("Üst sınıf adı bekleniyor")?)
+        } else {
+            None
+        };
+        self.expect(TokenKind::LBrace)?;
+        let mut fields = Vec::new();
+        let mut methods = Vec::new();
+        loop {
+            let doc = self.parse_doc_comment();
+            let tok = self.current();
+            match tok.kind {
+                TokenKind::RBrace => {
+                    self.advance();
+                    break;
+                }
+                TokenKind::Let => {
+                    if let Stmt::Let { name, value, .. } = self.parse_let()? {
+                        fields.push((name, value));
+                    }
+                }
+                TokenKind::Func => methods.push(self.parse_func()?.with_doc(doc)),
+                TokenKind::Async => methods.push(self.parse_async_func()?.with_doc(doc)),
+                TokenKind::Eof => return Err(self.error_at(&tok, "Sınıf gövdesi kapatılmadı, '}' bekleniyor")),
+                _ => {
+                    let message = format!(
+                        "Sınıf gövdesinde {} ya da {} bekleniyor",
+                        self.keyword(TokenKind::Let),
+                        self.keyword(TokenKind::Func)
+                    );
+                    return Err(self.error_at(&tok, &message));
+                }
+            }
+        }
+        Ok(Stmt::Class { name, base, base_slot: None, fields, methods, doc: None, span: self.span_from(&class_tok) })
+    }
+
+    fn parse_export(&mut self) -> Result<Stmt, NovaError> {
+        // export let/func/async func/class
+        let export_tok = self.advance().unwrap(); // 'export'
+        let decl = match self.current().kind {
+            TokenKind::Let => self.parse_let()?,
+            TokenKind::Func => self.parse_func()?,
+            TokenKind::Async => self.parse_async_func()?,
+            TokenKind::Class => self.parse_class()?,
+            _ => {
+                let message = format!(
+                    "{} sonrası {}, {} veya {} bekleniyor",
+                    self.keyword(TokenKind::Export),
+                    self.keyword(TokenKind::Let),
+                    self.keyword(TokenKind::Func),
+                    self.keyword(TokenKind::Class)
+                );
+                return Err(self.error_at(&self.current(), &message));
+            }
+        };
+        let name = match &decl {
+            Stmt::Let { name, .. }
+            | Stmt::Func { name, .. }
+            | Stmt::AsyncFunc { name, .. }
+            | Stmt::Class { name, .. } => name.clone(),
+            _ => unreachable!("export yalnızca bildirimleri sarar"),
+        };
+        Ok(Stmt::Export { name, decl: Box::new(decl), doc: None, span: self.span_from(&export_tok) })
+    }
+
+    fn parse_async_func(&mut self) -> Result<Stmt, NovaError> {
+        let async_tok = self.advance().unwrap(); // 'async'
+        if !self.check(&TokenKind::Func) {
+            let message = format!("{} sonrası {} bekleniyor", self.keyword(TokenKind::Async), self.keyword(TokenKind::Func));
+            return Err(self.error_at(&self.current(), &message));
+        }
+        let (name, params, body) = self.parse_func_parts()?;
+        Ok(Stmt::AsyncFunc { name, params, body, doc: None, span: self.span_from(&async_tok) })
+    }
+
+    fn parse_await(&mut self) -> Result<Expr, NovaError> {
+        let await_tok = self.advance().unwrap(); // 'await'
+        let expr = self.parse_unary()?;
+        let span = await_tok.span.to(expr.span());
+        Ok(Expr::Await { expr: Box::new(expr), span })
+    }
+
+    fn parse_try_catch_finally(&mut self) -> Result<Stmt, NovaError> {
+        // try { ... } catch (err[: Tür]) { ... } ... [finally { ... }] ya da try { ... } finally { ... }
+        let try_tok = self.advance().unwrap(); // 'try'
+        self.expect(TokenKind::LBrace)?;
+        let try_block = self.parse_block()?;
+        let mut catches = Vec::new();
+        while self.check(&TokenKind::Catch) {
+            catches.push(self.parse_catch()?);
+        }
+        if !self.match_token(&TokenKind::Finally) {
+            if catches.is_empty() {
+                let message = format!(
+                    "{} bloğundan sonra {} ya da {} bekleniyor",
+                    self.keyword(TokenKind::Try),
+                    self.keyword(TokenKind::Catch),
+                    self.keyword(TokenKind::Finally)
+                );
+                return Err(self.error_at(&self.current(), &message));
+            }
+            return Ok(Stmt::TryCatch { try_block, catches, span: self.span_from(&try_tok) });
+        }
+        self.expect(TokenKind::LBrace)?;
+        let finally_block = self.parse_block()?;
+        Ok(Stmt::TryCatchFinally { try_block, catches, finally_block, span: self.span_from(&try_tok) })
+    }
+
+    /// `obj.metot(args)` çağrısı ya da `obj.alan` erişimi.
+    fn parse_method_call(&mut self, object: Expr) -> Result<Expr, NovaError> {
+        self.expect(TokenKind::Dot)?;
+        let name = self.expect_identifier("'.' sonrası alan ya da metot adı bekleniyor")?;
+        if !self.check(&TokenKind::LParen) {
+            let span = object.span().to(self.previous_span());
+            return Ok(Expr::Get { object: Box::new(object), name, span });
+        }
+        let args = self.parse_args()?;
+        let span = object.span().to(self.previous_span());
+        Ok(Expr::MethodCall { object: Box::new(object), method: name, args, span })
+    }
+
+    fn parse_new(&mut self) -> Result<Expr, NovaError> {
+        let new_tok = self.advance().unwrap(); // 'new'
+        let class_name = self.expect_identifier(&format!("{} sonrası sınıf adı bekleniyor", self.keyword(TokenKind::New)))?;
+        let args = self.parse_args()?;
+        Ok(Expr::New { class_name, args, span: self.span_from(&new_tok) })
+    }
+
+    // Diğer yardımcı parse fonksiyonları buraya eklenecek
+}
+
+/// Doc yorumları yalnızca func, async func, class ve export deyimlerinden hemen
+/// önce anlamlıdır; başka yerlerdeki `///` tokenları ayrıştırmadan önce atılır.
+fn keep_attached_docs(tokens: Vec<Token>) -> Vec<Token> {
+    let mut kept = Vec::with_capacity(tokens.len());
+    for (i, tok) in tokens.iter().enumerate() {
+        if let TokenKind::Comment(_) = tok.kind {
+            let next = tokens[i..].iter().find(|t| !matches!(t.kind, TokenKind::Comment(_)));
+            let documents = matches!(
+                next.map(|t| &t.kind),
+                Some(TokenKind::Func | TokenKind::Async | TokenKind::Class | TokenKind::Export)
+            );
+            if !documents {
+                continue;
+            }
+        }
+        kept.push(tok.clone());
+    }
+    kept
+}
+
+/// İkili operatörün önceliği ve AST'deki yazılışı; büyük sayı daha sıkı bağlar.
+fn binary_op(kind: &TokenKind) -> Option<(u8, &'static str)> {
+    let op = match kind {
+        TokenKind::Or => (1, "||"),
+        TokenKind::And => (2, "&&"),
+        TokenKind::Eq => (3, "=="),
+        TokenKind::Neq => (3, "!="),
+        TokenKind::Lt => (4, "<"),
+        TokenKind::Le => (4, "<="),
+        TokenKind::Gt => (4, ">"),
+        TokenKind::Ge => (4, ">="),
+        TokenKind::Plus => (5, "+"),
+        TokenKind::Minus => (5, "-"),
+        TokenKind::Star => (6, "*"),
+        TokenKind::Slash => (6, "/"),
+        TokenKind::Percent => (6, "%"),
+        _ => return None,
+    };
+    Some(op)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+
+    fn parse(input: &str) -> Result<Program, Vec<NovaError>> {
+        let mut lexer = Lexer::new(input);
+        let tokens = lexer.tokenize().unwrap();
+        Parser::new(tokens).with_keywords(lexer.keyword_set()).parse_program()
+    }
+
+    /// İfadeyi parantezli biçimde yazar; öncelik ve birleşme sırası böylece görünür.
+    fn show(expr: &Expr) -> String {
+        let list = |items: &[Expr]| items.iter().map(show).collect::<Vec<_>>().join(", ");
+        match expr {
+            Expr::Int(i, _) => i.to_string(),
+            Expr::Float(f, _) => f.to_string(),
+            Expr::String(s, _) => format!("{:?}", s),
+            Expr::Bool(b, _) => b.to_string(),
+            Expr::Null(_) => "null".to_string(),
+            Expr::Identifier(name, _, _) => name.clone(),
+            Expr::BinaryOp { left, op, right, .. } => format!("({} {} {})", show(left), op, show(right)),
+            Expr::UnaryOp { op, expr, .. } => format!("({}{})", op, show(expr)),
+            Expr::Range { start, end, inclusive, .. } => {
+                format!("({}{}{})", show(start), if *inclusive { "..=" } else { ".." }, show(end))
+            }
+            Expr::Call { func, args, .. } => format!("{}({})", show(func), list(args)),
+            Expr::Assign { name, value, .. } => format!("({} = {})", name, show(value)),
+            Expr::List(items, _) => format!("[{}]", list(items)),
+            Expr::Map(pairs, _) => {
+                let pairs: Vec<_> = pairs.iter().map(|(k, v)| format!("{}: {}", show(k), show(v))).collect();
+                format!("{{{}}}", pairs.join(", "))
+            }
+            Expr::Index { collection, index, .. } => format!("{}[{}]", show(collection), show(index)),
+            Expr::SetIndex { collection, index, value, .. } => {
+                format!("({}[{}] = {})", show(collection), show(index), show(value))
+            }
+            Expr::Get { object, name, .. } => format!("{}.{}", show(object), name),
+            Expr::Set { object, name, value, .. } => format!("({}.{} = {})", show(object), name, show(value)),
+            Expr::MethodCall { object, method, args, .. } => format!("{}.{}({})", show(object), method, list(args)),
+            Expr::New { class_name, args, .. } => format!("new {}({})", class_name, list(args)),
+            Expr::Await { expr, .. } => format!("(await {})", show(expr)),
+            Expr::Lambda { params, body, .. } => format!("(({}) => {})", params.join(", "), show(body)),
+            Expr::Interpolation { parts, .. } => format!("`{}`", list(parts)),
+            Expr::Object { class_name, .. } => format!("<{}>", class_name),
+        }
+    }
+
+    fn expr(input: &str) -> String {
+        let program = parse(&format!("{};", input)).unwrap();
+        match &program.body[..] {
+            [Stmt::Expr(expr)] => show(expr),
+            other => panic!("tek ifade deyimi bekleniyordu: {:?}", other),
+        }
+    }
+
+    fn errors(input: &str) -> Vec<String> {
+        parse(input).unwrap_err().into_iter().map(|e| format!("{}:{} {}", e.line, e.col, e.message)).collect()
+    }
+
+    #[test]
+    fn parses_every_statement_form() {
+        let program = parse(
+            r#"
+            import math;
+            let x = 1;
+            /// Belgeli fonksiyon
+            func f(a, b) { return a; }
+            async func g() { return await f(1, 2); }
+            if (x) { x = 2; } else if (x == 2) { } else { }
+            while (x < 3) { x = x + 1; if (x == 2) { continue; } break; }
+            for i in 0..3 { }
+            for k, v in {"a": 1} { }
+            { let scoped = 1; }
+            try { throw "e"; } catch (e: TypeError) { } catch (e) { } finally { }
+            try { } finally { }
+            class A : B { let field = 1; let empty; func m() { return this.field; } }
+            export let y = 2;
+            export func h() { }
+            print(x);
+            "#,
+        )
+        .unwrap();
+        let names: Vec<&str> = program
+            .body
+            .iter()
+            .map(|stmt| match stmt {
+                Stmt::Import { .. } => "import",
+                Stmt::Let { .. } => "let",
+                Stmt::Func { doc: Some(_), .. } => "func+doc",
+                Stmt::AsyncFunc { .. } => "async",
+                Stmt::If { else_branch: Some(_), .. } => "if-else",
+                Stmt::While { .. } => "while",
+                Stmt::For { value_name: None, .. } => "for",
+                Stmt::For { value_name: Some(_), .. } => "for-pair",
+                Stmt::Block(..) => "block",
+                Stmt::TryCatchFinally { catches, .. } if catches.len() == 2 => "try-2catch-finally",
+                Stmt::TryCatchFinally { catches, .. } if catches.is_empty() => "try-finally",
+                Stmt::Class { base: Some(_), fields, methods, .. } if fields.len() == 2 && methods.len() == 1 => "class",
+                Stmt::Export { decl, .. } if matches!(**decl, Stmt::Let { .. }) => "export-let",
+                Stmt::Export { .. } => "export-func",
+                Stmt::Expr(_) => "expr",
+                other => panic!("beklenmeyen deyim: {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "import", "let", "func+doc", "async", "if-else", "while", "for", "for-pair", "block",
+                "try-2catch-finally", "try-finally", "class", "export-let", "export-func", "expr",
+            ]
+        );
+    }
+
+    #[test]
+    fn turkish_keywords_parse_the_same_statements() {
+        let program = parse("// dil: tr\ndeğişken x = doğru;\neğer (x) { döndür_yok(); } değilse { fırlat boş; }").unwrap();
+        assert!(matches!(program.body[0], Stmt::Let { .. }));
+        assert!(matches!(program.body[1], Stmt::If { else_branch: Some(_), .. }));
+    }
+
+    #[test]
+    fn respects_operator_precedence_and_associativity() {
+        assert_eq!(expr("1 + 2 * 3 - 4"), "((1 + (2 * 3)) - 4)");
+        assert_eq!(expr("a - b - c"), "((a - b) - c)");
+        assert_eq!(expr("a || b && c == d < e + f * -g"), "(a || (b && (c == (d < (e + (f * (-g)))))))");
+        assert_eq!(expr("!a == b"), "((!a) == b)");
+        assert_eq!(expr("x = y = 1 + 2"), "(x = (y = (1 + 2)))");
+        assert_eq!(expr("(1 + 2) * 3"), "((1 + 2) * 3)");
+        assert_eq!(expr("o.a.b(1)[2].c = 3"), "(o.a.b(1)[2].c = 3)");
+        assert_eq!(expr("xs[i + 1] = -xs[0]"), "(xs[(i + 1)] = (-xs[0]))");
+        assert_eq!(expr("f(1)(2)"), "f(1)(2)");
+        assert_eq!(expr("(a, b) => a + b"), "((a, b) => (a + b))");
+        assert_eq!(expr("\"t ${a + 1}\""), "`\"t \", (a + 1)`");
+    }
+
+    #[test]
+    fn reports_every_error_after_recovering() {
+        let found = errors("let = 1;\nlet ok = 2;\nfunc (x) { }\nprint(1 +);\nlet z = 3 4;");
+        assert_eq!(found.len(), 4, "{:#?}", found);
+        assert!(found[0].starts_with("1:5 "));
+        assert!(found[1].starts_with("3:6 "));
+        assert!(found[2].starts_with("4:10 "));
+        assert!(found[3].starts_with("5:11 "));
+    }
+
+    #[test]
+    fn recovery_skips_the_keyword_that_caused_the_error() {
+        let found = errors("let let = 1;\nprint(2);");
+        assert_eq!(found, ["1:5 let sonrası değişken adı bekleniyor"]);
+        // Hatadan sonra gelen deyim anahtar kelimesi yeni bir deyim başlatır
+        assert_eq!(errors(") let x = 1;\nlet = 2;").len(), 2);
+    }
+
+    #[test]
+    fn messages_use_the_file_keyword_language() {
+        assert_eq!(errors("// dil: tr\ndeğişken 5 = 1;"), ["2:10 değişken sonrası değişken adı bekleniyor"]);
+        assert_eq!(errors("// dil: tr\ndene { }"), ["2:9 dene bloğundan sonra yakala ya da sonunda bekleniyor"]);
+    }
+}
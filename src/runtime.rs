@@ -1,143 +1,470 @@
-// src/runtime.rs
-// NovaLang için kapsamlı runtime ve yerleşik fonksiyonlar
-
-use crate::vm::Value;
-use std::collections::HashMap;
-
-pub type BuiltinFunc = fn(Vec<Value>) -> Value;
-
-pub struct Runtime {
-    pub builtins: HashMap<String, BuiltinFunc>,
-}
-
-impl Runtime {
-    pub fn new() -> Self {
-        let mut builtins = HashMap::new();
-        builtins.insert("print".to_string(), builtin_print as BuiltinFunc);
-        builtins.insert("len".to_string(), builtin_len as BuiltinFunc);
-        builtins.insert("toString".to_string(), builtin_to_string as BuiltinFunc);
-        builtins.insert("keys".to_string(), builtin_keys as BuiltinFunc);
-        builtins.insert("readFile".to_string(), builtin_read_file as BuiltinFunc);
-        builtins.insert("writeFile".to_string(), builtin_write_file as BuiltinFunc);
-        builtins.insert("toUpper".to_string(), builtin_to_upper as BuiltinFunc);
-        builtins.insert("toLower".to_string(), builtin_to_lower as BuiltinFunc);
-        builtins.insert("httpGet".to_string(), builtin_http_get as BuiltinFunc);
-        // Diğer yerleşik fonksiyonlar buraya eklenebilir
-        Runtime { builtins }
-    }
-
-    pub fn load_std_module(name: &str) -> Option<String> {
-        match name {
-            "math" => Some("func sqrt(x) { /* ... */ }".to_string()),
-            "string" => Some("func upper(s) { /* ... */ }".to_string()),
-            "file" => Some("func read(path) { /* ... */ }".to_string()),
-            _ => None,
-        }
-    }
-}
-
-fn builtin_print(args: Vec<Value>) -> Value {
-    for arg in args {
-        match arg {
-            Value::Int(i) => print!("{} ", i),
-            Value::Float(f) => print!("{} ", f),
-            Value::String(s) => print!("{} ", s),
-            Value::Bool(b) => print!("{} ", b),
-            Value::Null => print!("null "),
-            Value::List(list) => {
-                print!("[");
-                for (i, v) in list.iter().enumerate() {
-                    if i > 0 { print!", "; }
-                    builtin_print(vec![v.clone()]);
-                }
-                print!("] ");
-            }
-            Value::Map(map) => {
-                print!("{{");
-                let mut first = true;
-                for (k, v) in map.iter() {
-                    if !first { print!", "; } else { first = false; }
-                    print!("{}: ", k);
-                    builtin_print(vec![v.clone()]);
-                }
-                print!("}} ");
-            }
-            _ => print!("<fn> "),
-        }
-    }
-    println!("");
-    Value::Null
-}
-
-fn builtin_len(args: Vec<Value>) -> Value {
-    if let Some(Value::String(s)) = args.get(0) {
-        Value::Int(s.len() as i64)
-    } else {
-        Value::Null
-    }
-}
-
-fn builtin_to_string(args: Vec<Value>) -> Value {
-    if let Some(val) = args.get(0) {
-        Value::String(format!("{:?}", val))
-    } else {
-        Value::Null
-    }
-}
-
-fn builtin_keys(args: Vec<Value>) -> Value {
-    if let Some(Value::Map(map)) = args.get(0) {
-        let keys = map.keys().cloned().map(Value::String).collect();
-        Value::List(keys)
-    } else {
-        Value::Null
-    }
-}
-
-fn builtin_read_file(args: Vec<Value>) -> Value {
-    if let Some(Value::String(path)) = args.get(0) {
-        match std::fs::read_to_string(path) {
-            Ok(content) => Value::String(content),
-            Err(_) => Value::Null,
-        }
-    } else {
-        Value::Null
-    }
-}
-
-fn builtin_write_file(args: Vec<Value>) -> Value {
-    if let (Some(Value::String(path)), Some(Value::String(content))) = (args.get(0), args.get(1)) {
-        match std::fs::write(path, content) {
-            Ok(_) => Value::Bool(true),
-            Err(_) => Value::Bool(false),
-        }
-    } else {
-        Value::Null
-    }
-}
-
-fn builtin_to_upper(args: Vec<Value>) -> Value {
-    if let Some(Value::String(s)) = args.get(0) {
-        Value::String(s.to_uppercase())
-    } else {
-        Value::Null
-    }
-}
-
-fn builtin_to_lower(args: Vec<Value>) -> Value {
-    if let Some(Value::String(s)) = args.get(0) {
-        Value::String(s.to_lowercase())
-    } else {
-        Value::Null
-    }
-}
-
-// Basit HTTP GET (reqwest veya std ile)
-fn builtin_http_get(args: Vec<Value>) -> Value {
-    if let Some(Value::String(url)) = args.get(0) {
-        // Burada async/await ile gerçek HTTP isteği yapılabilir
-        Value::String(format!("<dummy response for {}>", url))
-    } else {
-        Value::Null
-    }
+// src/runtime.rs
+// NovaLang için kapsamlı runtime ve yerleşik fonksiyonlar
+
+use crate::error::{NovaError, NovaErrorKind};
+use crate::span::Span;
+use crate::vm::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+pub type BuiltinFunc = fn(Vec<Value>) -> Value;
+
+pub struct Runtime {
+    pub builtins: HashMap<String, BuiltinFunc>,
+}
+
+impl Runtime {
+    pub fn new() -> Self {
+        let mut builtins = HashMap::new();
+        builtins.insert("print".to_string(), builtin_print as BuiltinFunc);
+        builtins.insert("len".to_string(), builtin_len as BuiltinFunc);
+        builtins.insert("toString".to_string(), builtin_to_string as BuiltinFunc);
+        builtins.insert("keys".to_string(), builtin_keys as BuiltinFunc);
+        builtins.insert("readFile".to_string(), builtin_read_file as BuiltinFunc);
+        builtins.insert("writeFile".to_string(), builtin_write_file as BuiltinFunc);
+        builtins.insert("toUpper".to_string(), builtin_to_upper as BuiltinFunc);
+        builtins.insert("toLower".to_string(), builtin_to_lower as BuiltinFunc);
+        builtins.insert("httpGet".to_string(), builtin_http_get as BuiltinFunc);
+        builtins.insert("push".to_string(), builtin_push as BuiltinFunc);
+        builtins.insert("pop".to_string(), builtin_pop as BuiltinFunc);
+        builtins.insert("same".to_string(), builtin_same as BuiltinFunc);
+        // Diğer yerleşik fonksiyonlar buraya eklenebilir
+        Runtime { builtins }
+    }
+
+    // stdlib/ modülleri henüz import'a bağlanmadı
+    #[allow(dead_code)]
+    pub fn load_std_module(name: &str) -> Option<String> {
+        match name {
+            "math" => Some("func sqrt(x) { /* ... */ }".to_string()),
+            "string" => Some("func upper(s) { /* ... */ }".to_string()),
+            "file" => Some("func read(path) { /* ... */ }".to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// İki motorun da izin verdiği en derin çağrı zinciri; sonsuz özyineleme Rust yığınını
+/// değil bu sınırı aşar.
+pub const FRAMES_MAX: usize = 4096;
+pub const STACK_OVERFLOW: &str = "Çağrı yığını taştı (çok derin özyineleme)";
+
+/// Üst sınıf olarak sınıf olmayan bir değer verildiğinde iki motorun verdiği hata.
+pub fn not_a_class(name: &str) -> String {
+    format!("'{}' bir sınıf değil; üst sınıf olarak kullanılamaz", name)
+}
+
+/// Bir değeri `print`, `toString` ve string interpolasyonunun kullandığı biçimde yazıya çevirir.
+/// Kendini içeren liste, map ya da nesne ikinci kez görüldüğünde `[...]`, `{...}` olarak yazılır.
+pub fn format_value(val: &Value) -> String {
+    format_in(val, &mut Vec::new())
+}
+
+// `open`: biçimlendirilmekte olan kapların adresleri (dıştan içe)
+fn format_in(val: &Value, open: &mut Vec<*const ()>) -> String {
+    let ptr = match val {
+        Value::List(list) => Rc::as_ptr(list) as *const (),
+        Value::Map(map) => Rc::as_ptr(map) as *const (),
+        Value::Object(obj) => Rc::as_ptr(obj) as *const (),
+        _ => std::ptr::null(),
+    };
+    if !ptr.is_null() {
+        if open.contains(&ptr) {
+            return if matches!(val, Value::List(_)) { "[...]" } else { "{...}" }.to_string();
+        }
+        open.push(ptr);
+    }
+    let text = match val {
+        Value::Int(i) => i.to_string(),
+        Value::Float(f) => f.to_string(),
+        Value::String(s) => s.clone(),
+        Value::Bool(b) => b.to_string(),
+        Value::Null => "null".to_string(),
+        Value::List(list) => {
+            let items: Vec<String> = list.borrow().iter().map(|item| format_in(item, open)).collect();
+            format!("[{}]", items.join(", "))
+        }
+        // Map'ler de nesneler gibi anahtar sırasıyla yazılır; iki motorun çıktısı aynı kalır
+        Value::Map(map) => {
+            let map = map.borrow();
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let items: Vec<String> = keys.iter().map(|k| format!("{}: {}", k, format_in(&map[*k], open))).collect();
+            format!("{{{}}}", items.join(", "))
+        }
+        Value::Class(class) => format!("<class {}>", class.name),
+        Value::Range(start, end, inclusive) => format!("{}..{}{}", start, if *inclusive { "=" } else { "" }, end),
+        Value::Object(obj) => {
+            let obj = obj.borrow();
+            let mut names: Vec<&String> = obj.fields.keys().collect();
+            names.sort();
+            let items: Vec<String> = names.iter().map(|k| format!("{}: {}", k, format_in(&obj.fields[*k], open))).collect();
+            format!("{} {{{}}}", obj.class.name, items.join(", "))
+        }
+        Value::Error(err) => format!("{}: {}", err.kind.name(), err.message),
+        _ => "<fn>".to_string(),
+    };
+    if !ptr.is_null() {
+        open.pop();
+    }
+    text
+}
+
+/// `==` karşılaştırması: listeler ve map'ler içerikleriyle (yapısal), nesneler
+/// kimlikleriyle karşılaştırılır. Int ve Float sayısal değerle eşitlenir.
+pub fn values_equal(a: &Value, b: &Value) -> bool {
+    equal_in(a, b, &mut Vec::new())
+}
+
+// `seen`: karşılaştırılmakta olan kap çiftleri. Döngüsel yapılarda aynı çifte
+// yeniden gelinirse çift eşit varsayılır; fark varsa başka bir dalda bulunur.
+fn equal_in(a: &Value, b: &Value, seen: &mut Vec<(*const (), *const ())>) -> bool {
+    match (a, b) {
+        (Value::Int(x), Value::Int(y)) => x == y,
+        (Value::Float(x), Value::Float(y)) => x == y,
+        (Value::Int(x), Value::Float(y)) | (Value::Float(y), Value::Int(x)) => *x as f64 == *y,
+        (Value::String(x), Value::String(y)) => x == y,
+        (Value::Bool(x), Value::Bool(y)) => x == y,
+        (Value::Null, Value::Null) => true,
+        (Value::List(x), Value::List(y)) => {
+            let pair = (Rc::as_ptr(x) as *const (), Rc::as_ptr(y) as *const ());
+            if Rc::ptr_eq(x, y) || seen.contains(&pair) {
+                return true;
+            }
+            seen.push(pair);
+            let (x, y) = (x.borrow(), y.borrow());
+            x.len() == y.len() && x.iter().zip(y.iter()).all(|(a, b)| equal_in(a, b, seen))
+        }
+        (Value::Map(x), Value::Map(y)) => {
+            let pair = (Rc::as_ptr(x) as *const (), Rc::as_ptr(y) as *const ());
+            if Rc::ptr_eq(x, y) || seen.contains(&pair) {
+                return true;
+            }
+            seen.push(pair);
+            let (x, y) = (x.borrow(), y.borrow());
+            x.len() == y.len() && x.iter().all(|(k, v)| y.get(k).is_some_and(|w| equal_in(v, w, seen)))
+        }
+        (Value::Object(x), Value::Object(y)) => Rc::ptr_eq(x, y),
+        (Value::Error(x), Value::Error(y)) => Rc::ptr_eq(x, y),
+        (Value::Class(x), Value::Class(y)) => Rc::ptr_eq(x, y),
+        // `0..=3` ile `0..4` aynı sayıları kapsar
+        (Value::Range(a, b, x), Value::Range(c, d, y)) => a == c && exclusive_end(*b, *x) == exclusive_end(*d, *y),
+        _ => false,
+    }
+}
+
+/// `same(a, b)`: iki değer aynı liste, map ya da nesneyi mi gösteriyor? Diğer
+/// değerler için `==` ile aynıdır.
+pub fn values_identical(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::List(x), Value::List(y)) => Rc::ptr_eq(x, y),
+        (Value::Map(x), Value::Map(y)) => Rc::ptr_eq(x, y),
+        _ => values_equal(a, b),
+    }
+}
+
+/// `obj.alan` okuması; hata mesajı her iki yorumlayıcıda aynıdır.
+pub fn get_field(object: &Value, name: &str) -> Result<Value, String> {
+    match object {
+        Value::Object(obj) => {
+            let obj = obj.borrow();
+            obj.fields.get(name).cloned().ok_or_else(|| format!("'{}' nesnesinde '{}' alanı yok", obj.class.name, name))
+        }
+        Value::Error(err) => match name {
+            "message" => Ok(Value::String(err.message.clone())),
+            "kind" => Ok(Value::String(err.kind.name())),
+            "line" => Ok(Value::Int(err.line as i64)),
+            "col" => Ok(Value::Int(err.col as i64)),
+            "stack" => Ok(Value::list(err.stack.iter().cloned().map(Value::String).collect())),
+            "value" => Ok(err.value.clone().unwrap_or(Value::Null)),
+            _ => Err(format!("Hata nesnesinde '{}' alanı yok", name)),
+        },
+        _ => Err("Alan erişimi yalnızca nesnelerde yapılabilir".to_string()),
+    }
+}
+
+/// `throw` ile fırlatılan değerden hata kurar. Yakalanmış bir hata nesnesi yeniden
+/// fırlatılırsa türü, konumu ve çağrı yığını olduğu gibi korunur. Nesnenin türü sınıf
+/// adıdır ve string `message` alanı varsa mesaj odur; özgün değer `e.value` ile okunur.
+pub fn thrown(val: Value, span: Span) -> NovaError {
+    let (kind, message) = match &val {
+        Value::Error(err) => return (**err).clone(),
+        Value::Object(obj) => {
+            let obj = obj.borrow();
+            let message = match obj.fields.get("message") {
+                Some(Value::String(message)) => message.clone(),
+                _ => format_value(&val),
+            };
+            (NovaErrorKind::Custom(obj.class.name.clone()), message)
+        }
+        other => (NovaErrorKind::Error, format_value(other)),
+    };
+    NovaError::at(kind, &message, span).with_value(val)
+}
+
+pub fn set_field(object: &Value, name: &str, val: Value) -> Result<(), String> {
+    match object {
+        Value::Object(obj) => {
+            obj.borrow_mut().fields.insert(name.to_string(), val);
+            Ok(())
+        }
+        _ => Err("Alan ataması yalnızca nesnelerde yapılabilir".to_string()),
+    }
+}
+
+pub fn get_index(collection: &Value, index: &Value) -> Result<Value, String> {
+    match (collection, index) {
+        (Value::List(list), Value::Int(i)) => {
+            let list = list.borrow();
+            list_slot(list.len(), *i).map(|idx| list[idx].clone()).ok_or_else(|| "Liste indeksi geçersiz".to_string())
+        }
+        (Value::Map(map), Value::String(s)) => {
+            map.borrow().get(s).cloned().ok_or_else(|| "Map anahtarı bulunamadı".to_string())
+        }
+        _ => Err("Geçersiz index işlemi".to_string()),
+    }
+}
+
+pub fn set_index(collection: &Value, index: &Value, val: Value) -> Result<(), String> {
+    match (collection, index) {
+        (Value::List(list), Value::Int(i)) => {
+            let mut list = list.borrow_mut();
+            let idx = list_slot(list.len(), *i).ok_or_else(|| "Liste indeksi geçersiz".to_string())?;
+            list[idx] = val;
+            Ok(())
+        }
+        // Map'e atama yeni anahtar da ekleyebilir
+        (Value::Map(map), Value::String(s)) => {
+            map.borrow_mut().insert(s.clone(), val);
+            Ok(())
+        }
+        _ => Err("Geçersiz index ataması".to_string()),
+    }
+}
+
+/// Hata mesajlarında kullanılan tür adı.
+pub fn type_name(val: &Value) -> &'static str {
+    match val {
+        Value::Int(_) => "int",
+        Value::Float(_) => "float",
+        Value::String(_) => "string",
+        Value::Bool(_) => "bool",
+        Value::Null => "null",
+        Value::Func { .. } | Value::Closure(_) => "fonksiyon",
+        Value::List(_) => "liste",
+        Value::Map(_) => "map",
+        Value::Class(_) => "sınıf",
+        Value::Object(_) => "nesne",
+        Value::Range(..) => "aralık",
+        Value::Iterator(_) => "yineleyici",
+        Value::Error(_) => "hata",
+    }
+}
+
+/// `start..end` ve `start..=end` değerini kurar; sınırlar tamsayı olmalıdır.
+/// Aralığın hariç bitişi; `..=` aralıklarında bitişin bir fazlası.
+fn exclusive_end(end: i64, inclusive: bool) -> i64 {
+    if inclusive {
+        end + 1
+    } else {
+        end
+    }
+}
+
+pub fn make_range(start: &Value, end: &Value, inclusive: bool) -> Result<Value, String> {
+    match (start, end) {
+        (Value::Int(start), Value::Int(end)) => {
+            // Dahil bitişin bir fazlası yinelemede sınır olarak kullanılır, taşmamalı
+            if inclusive && *end == i64::MAX {
+                return Err("Aralık sınırı çok büyük".to_string());
+            }
+            Ok(Value::Range(*start, *end, inclusive))
+        }
+        _ => Err(format!("Aralık sınırları int olmalı, '{}' ve '{}' verildi", type_name(start), type_name(end))),
+    }
+}
+
+/// for-in yineleme protokolü. Liste, map, string ve aralıklar burada gezilir;
+/// `iter()`/`next()` tanımlayan nesnelerin metotlarını çalışan motor çağırır.
+#[derive(Debug)]
+pub enum Iter {
+    // Döngü başındaki uzunluk kadar gezilir: döngüde eklenen öğeler gezilmez,
+    // yerinde değiştirilen öğeler güncel halleriyle okunur
+    List { list: Rc<RefCell<Vec<Value>>>, index: usize, len: usize },
+    // Map döngü başındaki haliyle, anahtar sırasına göre gezilir
+    Map(Vec<(String, Value)>, usize),
+    Chars(Vec<char>, usize),
+    Range { next: i64, end: i64, index: i64 },
+    Object(Value),
+}
+
+impl Iter {
+    /// Nesne dışındaki yinelenebilir değerler için yineleyici.
+    pub fn new(val: &Value) -> Result<Iter, String> {
+        match val {
+            Value::List(list) => Ok(Iter::List { list: list.clone(), index: 0, len: list.borrow().len() }),
+            Value::Map(map) => {
+                let mut pairs: Vec<(String, Value)> = map.borrow().iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+                pairs.sort_by(|a, b| a.0.cmp(&b.0));
+                Ok(Iter::Map(pairs, 0))
+            }
+            Value::String(s) => Ok(Iter::Chars(s.chars().collect(), 0)),
+            Value::Range(start, end, inclusive) => Ok(Iter::Range { next: *start, end: exclusive_end(*end, *inclusive), index: 0 }),
+            _ => Err(format!("'{}' değeri for döngüsüyle gezilemez", type_name(val))),
+        }
+    }
+
+    /// Sıradaki öğe. Tek değişkenli döngüde map anahtarı, diğerlerinde öğenin kendisi
+    /// döner; iki değişkenli döngüde (anahtar, değer) ya da (sıra, öğe) döner.
+    /// Nesne yineleyicileri burada ilerlemez.
+    pub fn next(&mut self, pair: bool) -> Option<(Value, Value)> {
+        let (key, item) = match self {
+            Iter::List { list, index, len } => {
+                if *index >= *len {
+                    return None;
+                }
+                // Döngüde kısalan listenin sonuna gelindiyse gezinti biter
+                let item = list.borrow().get(*index).cloned()?;
+                *index += 1;
+                (Value::Int(*index as i64 - 1), item)
+            }
+            Iter::Map(pairs, i) => {
+                let (key, val) = pairs.get(*i).cloned()?;
+                *i += 1;
+                if !pair {
+                    return Some((Value::String(key), Value::Null));
+                }
+                (Value::String(key), val)
+            }
+            Iter::Chars(chars, i) => {
+                let ch = *chars.get(*i)?;
+                *i += 1;
+                (Value::Int(*i as i64 - 1), Value::String(ch.to_string()))
+            }
+            Iter::Range { next, end, index } => {
+                if *next >= *end {
+                    return None;
+                }
+                let item = (Value::Int(*index), Value::Int(*next));
+                *next += 1;
+                *index += 1;
+                item
+            }
+            Iter::Object(_) => return None,
+        };
+        Some(if pair { (key, item) } else { (item, Value::Null) })
+    }
+}
+
+/// Liste indeksini doğrular; sınır dışı ve negatif indeksler `None` döner.
+fn list_slot(len: usize, index: i64) -> Option<usize> {
+    usize::try_from(index).ok().filter(|&i| i < len)
+}
+
+fn builtin_print(args: Vec<Value>) -> Value {
+    let parts: Vec<String> = args.iter().map(format_value).collect();
+    println!("{}", parts.join(" "));
+    Value::Null
+}
+
+fn builtin_len(args: Vec<Value>) -> Value {
+    match args.first() {
+        Some(Value::String(s)) => Value::Int(s.chars().count() as i64),
+        Some(Value::List(list)) => Value::Int(list.borrow().len() as i64),
+        Some(Value::Map(map)) => Value::Int(map.borrow().len() as i64),
+        Some(Value::Range(start, end, inclusive)) => Value::Int(exclusive_end(*end, *inclusive).saturating_sub(*start).max(0)),
+        _ => Value::Null,
+    }
+}
+
+// Listeyi yerinde değiştirir; aynı listeyi gösteren tüm değişkenler değişikliği görür
+fn builtin_push(args: Vec<Value>) -> Value {
+    if let (Some(Value::List(list)), Some(val)) = (args.first(), args.get(1)) {
+        list.borrow_mut().push(val.clone());
+        Value::Int(list.borrow().len() as i64)
+    } else {
+        Value::Null
+    }
+}
+
+fn builtin_pop(args: Vec<Value>) -> Value {
+    if let Some(Value::List(list)) = args.first() {
+        list.borrow_mut().pop().unwrap_or(Value::Null)
+    } else {
+        Value::Null
+    }
+}
+
+fn builtin_same(args: Vec<Value>) -> Value {
+    match (args.first(), args.get(1)) {
+        (Some(a), Some(b)) => Value::Bool(values_identical(a, b)),
+        _ => Value::Bool(false),
+    }
+}
+
+fn builtin_to_string(args: Vec<Value>) -> Value {
+    if let Some(val) = args.first() {
+        Value::String(format_value(val))
+    } else {
+        Value::Null
+    }
+}
+
+fn builtin_keys(args: Vec<Value>) -> Value {
+    if let Some(Value::Map(map)) = args.first() {
+        let mut keys: Vec<String> = map.borrow().keys().cloned().collect();
+        keys.sort();
+        Value::list(keys.into_iter().map(Value::String).collect())
+    } else {
+        Value::Null
+    }
+}
+
+fn builtin_read_file(args: Vec<Value>) -> Value {
+    if let Some(Value::String(path)) = args.first() {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Value::String(content),
+            Err(_) => Value::Null,
+        }
+    } else {
+        Value::Null
+    }
+}
+
+fn builtin_write_file(args: Vec<Value>) -> Value {
+    if let (Some(Value::String(path)), Some(Value::String(content))) = (args.first(), args.get(1)) {
+        match std::fs::write(path, content) {
+            Ok(_) => Value::Bool(true),
+            Err(_) => Value::Bool(false),
+        }
+    } else {
+        Value::Null
+    }
+}
+
+fn builtin_to_upper(args: Vec<Value>) -> Value {
+    if let Some(Value::String(s)) = args.first() {
+        Value::String(s.to_uppercase())
+    } else {
+        Value::Null
+    }
+}
+
+fn builtin_to_lower(args: Vec<Value>) -> Value {
+    if let Some(Value::String(s)) = args.first() {
+        Value::String(s.to_lowercase())
+    } else {
+        Value::Null
+    }
+}
+
+// Basit HTTP GET (reqwest veya std ile)
+fn builtin_http_get(args: Vec<Value>) -> Value {
+    if let Some(Value::String(url)) = args.first() {
+        // Burada async/await ile gerçek HTTP isteği yapılabilir
+        Value::String(format!("<dummy response for {}>", url))
+    } else {
+        Value::Null
+    }
 } 
\ No newline at end of file
@@ -1,133 +1,570 @@
-// src/semantic.rs
-// NovaLang için kapsamlı semantik analiz ve tip kontrolü
-
-use crate::ast::{Expr, Stmt, Program};
-use crate::error::{NovaError, NovaErrorKind};
-use std::collections::HashMap;
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum Type {
-    Int,
-    Float,
-    String,
-    Bool,
-    Null,
-    Func(Vec<Type>, Box<Type>),
-    Unknown,
-    List(Box<Type>),
-    Map(Box<Type>, Box<Type>),
-}
-
-pub struct SemanticAnalyzer {
-    pub variables: HashMap<String, Type>,
-    pub functions: HashMap<String, (Vec<Type>, Type)>,
-}
-
-impl SemanticAnalyzer {
-    pub fn new() -> Self {
-        SemanticAnalyzer {
-            variables: HashMap::new(),
-            functions: HashMap::new(),
-        }
-    }
-
-    pub fn analyze(&mut self, program: &Program) -> Result<(), NovaError> {
-        for stmt in &program.body {
-            self.analyze_stmt(stmt)?;
-        }
-        Ok(())
-    }
-
-    fn analyze_stmt(&mut self, stmt: &Stmt) -> Result<(), NovaError> {
-        match stmt {
-            Stmt::Class { name, base, methods, .. } => {
-                // Sınıf adı, üst sınıf ve metotları kaydet
-                // ...
-                Ok(())
-            }
-            Stmt::Export { name, value, .. } => {
-                // Export edilen sembolü kaydet
-                // ...
-                Ok(())
-            }
-            Stmt::AsyncFunc { name, params, body, .. } => {
-                // Async fonksiyon kaydı
-                // ...
-                Ok(())
-            }
-            Stmt::TryCatchFinally { try_block, err_name, catch_block, finally_block, .. } => {
-                // Try, catch ve finally bloklarını analiz et
-                // ...
-                Ok(())
-            }
-            _ => Ok(()),
-        }
-    }
-
-    fn analyze_expr(&mut self, expr: &Expr) -> Result<Type, NovaError> {
-        match expr {
-            Expr::List(items, _, _) => {
-                // Listenin eleman tiplerini kontrol et
-                let mut elem_type = Type::Unknown;
-                for item in items {
-                    let t = self.analyze_expr(item)?;
-                    if elem_type == Type::Unknown {
-                        elem_type = t;
-                    } else if elem_type != t {
-                        elem_type = Type::Unknown; // Farklı tipler varsa Unknown
-                    }
-                }
-                Ok(Type::List(Box::new(elem_type)))
-            }
-            Expr::Map(pairs, _, _) => {
-                // Map anahtarları string olmalı, değer tipleri çıkarılır
-                let mut val_type = Type::Unknown;
-                for (k, v) in pairs {
-                    let kt = self.analyze_expr(k)?;
-                    if kt != Type::String {
-                        return Err(NovaError::new(NovaErrorKind::SemanticError, "Map anahtarı string olmalı", 0, 0, None));
-                    }
-                    let vt = self.analyze_expr(v)?;
-                    if val_type == Type::Unknown {
-                        val_type = vt;
-                    } else if val_type != vt {
-                        val_type = Type::Unknown;
-                    }
-                }
-                Ok(Type::Map(Box::new(Type::String), Box::new(val_type)))
-            }
-            Expr::Index { collection, index, .. } => {
-                let coll_type = self.analyze_expr(collection)?;
-                let idx_type = self.analyze_expr(index)?;
-                match coll_type {
-                    Type::List(_) if idx_type == Type::Int => Ok(Type::Unknown),
-                    Type::Map(_, v) if idx_type == Type::String => Ok(*v),
-                    _ => Err(NovaError::new(NovaErrorKind::SemanticError, "Geçersiz index işlemi", 0, 0, None)),
-                }
-            }
-            Expr::Object { class_name, fields } => {
-                // Nesne tipi kontrolü
-                // ...
-                Ok(Type::Unknown)
-            }
-            Expr::MethodCall { object, method, args, .. } => {
-                // Metot çağrısı tip kontrolü
-                // ...
-                Ok(Type::Unknown)
-            }
-            Expr::New { class_name, args, .. } => {
-                // new ile nesne oluşturma tipi
-                // ...
-                Ok(Type::Unknown)
-            }
-            Expr::Await { expr, .. } => {
-                // await edilen ifadenin tipi
-                // ...
-                Ok(Type::Unknown)
-            }
-            _ => Ok(Type::Unknown),
-        }
-    }
-
-    // Diğer yardımcı analiz fonksiyonları buraya eklenecek
-}
+// src/semantic.rs
+// NovaLang için kapsamlı semantik analiz ve tip kontrolü
+
+use crate::ast::{CatchClause, Expr, Stmt, Program, Slot};
+use crate::error::{NovaError, NovaErrorKind};
+use crate::span::Span;
+use std::collections::{HashMap, HashSet};
+
+// Değişken ve fonksiyon tipleri henüz çıkarılmıyor; yalnızca sabitlerden bilinen tipler denetlenir
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    Int,
+    Float,
+    String,
+    Bool,
+    Null,
+    Func(Vec<Type>, Box<Type>),
+    Unknown,
+    List(Box<Type>),
+    Map(Box<Type>, Box<Type>),
+}
+
+#[allow(dead_code)]
+pub struct SemanticAnalyzer {
+    pub variables: HashMap<String, Type>,
+    pub functions: HashMap<String, (Vec<Type>, Type)>,
+}
+
+impl SemanticAnalyzer {
+    pub fn new() -> Self {
+        SemanticAnalyzer {
+            variables: HashMap::new(),
+            functions: HashMap::new(),
+        }
+    }
+
+    pub fn analyze(&mut self, program: &Program) -> Result<(), NovaError> {
+        self.analyze_stmts(&program.body)
+    }
+
+    fn analyze_stmts(&mut self, stmts: &[Stmt]) -> Result<(), NovaError> {
+        for stmt in stmts {
+            self.analyze_stmt(stmt)?;
+        }
+        Ok(())
+    }
+
+    /// Deyimdeki tüm ifadeleri denetler; tipi `Unknown` olan ifadeler çalışma zamanına bırakılır.
+    fn analyze_stmt(&mut self, stmt: &Stmt) -> Result<(), NovaError> {
+        match stmt {
+            Stmt::Let { value: expr, .. } | Stmt::Expr(expr) | Stmt::Return(expr, _) | Stmt::Throw(expr, _) => {
+                self.analyze_expr(expr)?;
+                Ok(())
+            }
+            Stmt::Func { body, .. } | Stmt::AsyncFunc { body, .. } | Stmt::Block(body, _) => self.analyze_stmts(body),
+            Stmt::If { cond, then_branch, else_branch, .. } => {
+                self.analyze_expr(cond)?;
+                self.analyze_stmts(then_branch)?;
+                match else_branch {
+                    Some(else_branch) => self.analyze_stmts(else_branch),
+                    None => Ok(()),
+                }
+            }
+            Stmt::While { cond: expr, body, .. } | Stmt::For { iterable: expr, body, .. } => {
+                self.analyze_expr(expr)?;
+                self.analyze_stmts(body)
+            }
+            Stmt::TryCatch { try_block, catches, .. } => {
+                self.analyze_stmts(try_block)?;
+                self.analyze_catches(catches)
+            }
+            Stmt::TryCatchFinally { try_block, catches, finally_block, .. } => {
+                self.analyze_stmts(try_block)?;
+                self.analyze_catches(catches)?;
+                self.analyze_stmts(finally_block)
+            }
+            Stmt::Class { fields, methods, .. } => {
+                for (_, init) in fields {
+                    self.analyze_expr(init)?;
+                }
+                self.analyze_stmts(methods)
+            }
+            Stmt::Export { decl, .. } => self.analyze_stmt(decl),
+            Stmt::Break(_) | Stmt::Continue(_) | Stmt::Import { .. } => Ok(()),
+        }
+    }
+
+    fn analyze_catches(&mut self, catches: &[CatchClause]) -> Result<(), NovaError> {
+        for catch in catches {
+            self.analyze_stmts(&catch.body)?;
+        }
+        Ok(())
+    }
+
+    fn analyze_exprs(&mut self, exprs: &[Expr]) -> Result<(), NovaError> {
+        for expr in exprs {
+            self.analyze_expr(expr)?;
+        }
+        Ok(())
+    }
+
+    fn analyze_expr(&mut self, expr: &Expr) -> Result<Type, NovaError> {
+        match expr {
+            Expr::Int(..) => Ok(Type::Int),
+            Expr::Float(..) => Ok(Type::Float),
+            Expr::String(..) => Ok(Type::String),
+            Expr::Bool(..) => Ok(Type::Bool),
+            Expr::Null(_) => Ok(Type::Null),
+            Expr::List(items, _) => {
+                // Listenin eleman tiplerini kontrol et
+                let mut elem_type = Type::Unknown;
+                for item in items {
+                    let t = self.analyze_expr(item)?;
+                    if elem_type == Type::Unknown {
+                        elem_type = t;
+                    } else if elem_type != t {
+                        elem_type = Type::Unknown; // Farklı tipler varsa Unknown
+                    }
+                }
+                Ok(Type::List(Box::new(elem_type)))
+            }
+            Expr::Map(pairs, _) => {
+                // Map anahtarları string olmalı, değer tipleri çıkarılır
+                let mut val_type = Type::Unknown;
+                for (k, v) in pairs {
+                    let kt = self.analyze_expr(k)?;
+                    if !matches!(kt, Type::String | Type::Unknown) {
+                        return Err(NovaError::at(NovaErrorKind::SemanticError, "Map anahtarı string olmalı", k.span()));
+                    }
+                    let vt = self.analyze_expr(v)?;
+                    if val_type == Type::Unknown {
+                        val_type = vt;
+                    } else if val_type != vt {
+                        val_type = Type::Unknown;
+                    }
+                }
+                Ok(Type::Map(Box::new(Type::String), Box::new(val_type)))
+            }
+            Expr::Index { collection, index, span } => {
+                let coll_type = self.analyze_expr(collection)?;
+                let idx_type = self.analyze_expr(index)?;
+                match (coll_type, idx_type) {
+                    (Type::List(v), Type::Int) => Ok(*v),
+                    (Type::Map(_, v), Type::String) => Ok(*v),
+                    (Type::List(_) | Type::Map(..), Type::Unknown) | (Type::Unknown, _) => Ok(Type::Unknown),
+                    _ => Err(NovaError::at(NovaErrorKind::SemanticError, "Geçersiz index işlemi", *span)),
+                }
+            }
+            Expr::SetIndex { collection, index, value, .. } => {
+                self.analyze_expr(collection)?;
+                self.analyze_expr(index)?;
+                self.analyze_expr(value)
+            }
+            Expr::BinaryOp { left, right, .. } | Expr::Range { start: left, end: right, .. } => {
+                self.analyze_expr(left)?;
+                self.analyze_expr(right)?;
+                Ok(Type::Unknown)
+            }
+            Expr::UnaryOp { expr, .. } | Expr::Get { object: expr, .. } | Expr::Lambda { body: expr, .. } => {
+                self.analyze_expr(expr)?;
+                Ok(Type::Unknown)
+            }
+            Expr::Assign { value, .. } => self.analyze_expr(value),
+            Expr::Set { object, value, .. } => {
+                self.analyze_expr(object)?;
+                self.analyze_expr(value)
+            }
+            Expr::Call { func: callee, args, .. } | Expr::MethodCall { object: callee, args, .. } => {
+                self.analyze_expr(callee)?;
+                self.analyze_exprs(args)?;
+                Ok(Type::Unknown)
+            }
+            Expr::New { args, .. } => {
+                // new ile nesne oluşturma tipi
+                self.analyze_exprs(args)?;
+                Ok(Type::Unknown)
+            }
+            Expr::Await { expr, .. } => self.analyze_expr(expr),
+            Expr::Interpolation { parts, .. } => {
+                // Her değer print'in biçimiyle metne çevrilir; parçaların kendisi denetlenir,
+                // sonuç her zaman string'dir: `"${x}"[0]` gibi işlemler de buna göre denetlenir
+                self.analyze_exprs(parts)?;
+                Ok(Type::String)
+            }
+            Expr::Identifier(..) | Expr::Object { .. } => Ok(Type::Unknown),
+        }
+    }
+}
+
+/// Kapsam çözümleyici: her yerel değişken kullanımına ve atamasına `Slot` yazar,
+/// böylece VM yerel değişkenleri isimle değil konumla bulur.
+///
+/// Açılan kapsamlar VM'inkilerle birebir aynı olmalıdır: blok, if/else dalları,
+/// while gövdesi, try bloğu, hata değişkeniyle catch bloğu, fonksiyon ve lambda
+/// parametreleri, metotlarda ise parametrelerin üstünde `this`/`self`/`super`.
+/// Üst düzey isimler global sayılır ve isimle aranır.
+pub struct Resolver {
+    scopes: Vec<ResolverScope>,
+    // Önceki girdilerden (REPL, içe aktarılan modüller) ve yerleşiklerden bilinen isimler
+    known: HashSet<String>,
+    // Programın üst düzeyinde herhangi bir yerde bildirilen isimler
+    top_level: HashSet<String>,
+    top_declared: HashSet<String>,
+    function_level: usize,
+    // İçinde bulunulan fonksiyondaki iç içe döngü sayısı
+    loop_depth: usize,
+    has_imports: bool,
+    errors: Vec<NovaError>,
+}
+
+struct ResolverScope {
+    // isim -> (kapsamdaki sıra, başlatıcısı bitti mi)
+    names: HashMap<String, (usize, bool)>,
+    // Bu kapsamda daha aşağıda bildirilecek isimler
+    pending: HashSet<String>,
+    // Kapsamdaki fonksiyon bildirimleri; bytecode derleyicisi bunları kapsam başına
+    // aldığından iç fonksiyonlar onları bildirimden önce de yakalayabilir
+    hoisted: HashSet<String>,
+    function_level: usize,
+}
+
+impl Resolver {
+    pub fn new(known: HashSet<String>) -> Self {
+        Resolver {
+            scopes: Vec::new(),
+            known,
+            top_level: HashSet::new(),
+            top_declared: HashSet::new(),
+            function_level: 0,
+            loop_depth: 0,
+            has_imports: false,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Programı çözümler; bulunan tüm hatalar birlikte döndürülür.
+    pub fn resolve(&mut self, program: &mut Program) -> Result<(), Vec<NovaError>> {
+        self.top_level = declared_names(&program.body);
+        self.has_imports = program.body.iter().any(|stmt| matches!(stmt, Stmt::Import { .. }));
+        for stmt in &mut program.body {
+            self.resolve_stmt(stmt);
+        }
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(std::mem::take(&mut self.errors))
+        }
+    }
+
+    fn error(&mut self, message: &str, span: Span) {
+        self.errors.push(NovaError::at(NovaErrorKind::SemanticError, message, span));
+    }
+
+    fn resolve_stmt(&mut self, stmt: &mut Stmt) {
+        match stmt {
+            Stmt::Let { name, value, span } => {
+                self.declare(name, *span);
+                self.resolve_expr(value);
+                self.define(name);
+            }
+            Stmt::Expr(expr) => self.resolve_expr(expr),
+            Stmt::Return(expr, span) => {
+                if self.function_level == 0 {
+                    self.error("return yalnızca fonksiyon içinde kullanılabilir", *span);
+                }
+                self.resolve_expr(expr);
+            }
+            Stmt::Throw(expr, _) => self.resolve_expr(expr),
+            Stmt::Break(span) if self.loop_depth == 0 => self.error("break yalnızca döngü içinde kullanılabilir", *span),
+            Stmt::Continue(span) if self.loop_depth == 0 => self.error("continue yalnızca döngü içinde kullanılabilir", *span),
+            Stmt::Break(_) | Stmt::Continue(_) => {}
+            Stmt::Func { name, params, body, span, .. } | Stmt::AsyncFunc { name, params, body, span, .. } => {
+                // İsim gövdeden önce tanımlanır ki fonksiyon kendini çağırabilsin
+                self.declare(name, *span);
+                self.define(name);
+                self.resolve_function(params, body, *span);
+            }
+            Stmt::If { cond, then_branch, else_branch, .. } => {
+                self.resolve_expr(cond);
+                self.resolve_block(then_branch, &[]);
+                if let Some(else_branch) = else_branch {
+                    self.resolve_block(else_branch, &[]);
+                }
+            }
+            Stmt::While { cond, body, .. } => {
+                self.resolve_expr(cond);
+                self.loop_depth += 1;
+                self.resolve_block(body, &[]);
+                self.loop_depth -= 1;
+            }
+            // Döngü değişkenleri gövde kapsamının başında tanımlanır
+            Stmt::For { name, value_name, iterable, body, span } => {
+                self.resolve_expr(iterable);
+                let mut bindings = vec![(name.as_str(), *span)];
+                if let Some(value_name) = value_name {
+                    bindings.push((value_name.as_str(), *span));
+                }
+                self.loop_depth += 1;
+                self.resolve_block(body, &bindings);
+                self.loop_depth -= 1;
+            }
+            Stmt::Block(body, _) => self.resolve_block(body, &[]),
+            Stmt::TryCatch { try_block, catches, .. } => {
+                self.resolve_block(try_block, &[]);
+                for catch in catches {
+                    self.resolve_catch(catch);
+                }
+            }
+            Stmt::TryCatchFinally { try_block, catches, finally_block, .. } => {
+                self.resolve_block(try_block, &[]);
+                for catch in catches {
+                    self.resolve_catch(catch);
+                }
+                self.resolve_block(finally_block, &[]);
+            }
+            Stmt::Import { .. } => {}
+            Stmt::Class { name, base, base_slot, fields, methods, span, .. } => {
+                // Üst sınıf, sınıfın adı bağlanmadan önce çözülür: `class A : A {}` ve
+                // birbirinden türeyen yerel sınıflar "tanımlanmadan önce kullanıldı" hatası verir
+                if let Some(base_name) = base {
+                    *base_slot = self.lookup(base_name, *span);
+                }
+                self.declare(name, *span);
+                self.define(name);
+                // Alan başlatıcıları nesne oluşturulurken global ortamda çalışır
+                let scopes = std::mem::take(&mut self.scopes);
+                let level = std::mem::replace(&mut self.function_level, 0);
+                for (_, init) in fields.iter_mut() {
+                    self.resolve_expr(init);
+                }
+                self.scopes = scopes;
+                self.function_level = level;
+                for method in methods {
+                    if let Stmt::Func { params, body, span, .. } | Stmt::AsyncFunc { params, body, span, .. } = method {
+                        let mut receiver = vec!["this", "self"];
+                        if base.is_some() {
+                            receiver.push("super");
+                        }
+                        self.push_scope(&[]);
+                        for name in receiver {
+                            self.declare(name, *span);
+                            self.define(name);
+                        }
+                        self.resolve_function(params, body, *span);
+                        self.scopes.pop();
+                    }
+                }
+            }
+            Stmt::Export { decl, .. } => self.resolve_stmt(decl),
+        }
+    }
+
+    fn resolve_stmts(&mut self, stmts: &mut [Stmt]) {
+        for stmt in stmts {
+            self.resolve_stmt(stmt);
+        }
+    }
+
+    /// Yeni bir kapsamda bloğu çözümler; `bindings` blok başında tanımlanan isimlerdir.
+    fn resolve_block(&mut self, body: &mut [Stmt], bindings: &[(&str, Span)]) {
+        self.push_scope(body);
+        for (name, span) in bindings {
+            self.declare(name, *span);
+            self.define(name);
+        }
+        self.resolve_stmts(body);
+        self.scopes.pop();
+    }
+
+    fn resolve_catch(&mut self, catch: &mut CatchClause) {
+        if let Some(kind) = &catch.kind {
+            // Hata türlerinin yanında fırlatılabilecek sınıfların adları da yazılabilir
+            if !NovaErrorKind::is_name(kind) && self.is_undeclared(kind) {
+                self.error(&format!("Bilinmeyen hata türü: {}", kind), catch.span);
+            }
+        }
+        self.resolve_block(&mut catch.body, &[(catch.name.as_str(), catch.span)]);
+    }
+
+    fn resolve_function(&mut self, params: &[String], body: &mut [Stmt], span: Span) {
+        let loops = self.enter_function(params, body, span);
+        self.resolve_stmts(body);
+        self.exit_function(loops);
+    }
+
+    /// Parametrelerin ve gövdedeki bildirimlerin kapsamını açar; dıştaki döngü sayısını döndürür.
+    fn enter_function(&mut self, params: &[String], body: &[Stmt], span: Span) -> usize {
+        self.function_level += 1;
+        // Döngü içindeki fonksiyonun gövdesinden dıştaki döngü kırılamaz
+        let loops = std::mem::replace(&mut self.loop_depth, 0);
+        self.push_scope(body);
+        for param in params {
+            self.declare(param, span);
+            self.define(param);
+        }
+        loops
+    }
+
+    fn exit_function(&mut self, loops: usize) {
+        self.scopes.pop();
+        self.loop_depth = loops;
+        self.function_level -= 1;
+    }
+
+    /// `body` kapsamın deyimleridir; bildirdiği isimler henüz bildirilmemiş sayılır.
+    fn push_scope(&mut self, body: &[Stmt]) {
+        let scope = ResolverScope {
+            names: HashMap::new(),
+            pending: declared_names(body),
+            hoisted: declared_functions(body),
+            function_level: self.function_level,
+        };
+        self.scopes.push(scope);
+    }
+
+    fn declare(&mut self, name: &str, span: Span) {
+        let duplicate = match self.scopes.last_mut() {
+            Some(scope) => {
+                scope.pending.remove(name);
+                if scope.names.contains_key(name) {
+                    true
+                } else {
+                    let index = scope.names.len();
+                    scope.names.insert(name.to_string(), (index, false));
+                    false
+                }
+            }
+            None => !self.top_declared.insert(name.to_string()),
+        };
+        if duplicate {
+            self.error(&format!("'{}' bu kapsamda zaten tanımlı", name), span);
+        }
+    }
+
+    fn define(&mut self, name: &str) {
+        if let Some(entry) = self.scopes.last_mut().and_then(|scope| scope.names.get_mut(name)) {
+            entry.1 = true;
+        }
+    }
+
+    /// İsmi içten dışa kapsamlarda arar. Yerel değilse `None` döner ve VM ismi
+    /// kapsam zincirinde arar.
+    fn lookup(&mut self, name: &str, span: Span) -> Option<Slot> {
+        let mut before_declare = false;
+        for (depth, scope) in self.scopes.iter().rev().enumerate() {
+            if let Some(&(index, defined)) = scope.names.get(name) {
+                if !defined {
+                    self.error(&format!("'{}' kendi başlatıcısında kullanılamaz", name), span);
+                    return None;
+                }
+                return Some(Slot { depth, index });
+            }
+            // Başka bir fonksiyonun gövdesi yalnızca fonksiyon bildirimlerini erken görebilir;
+            // sonradan gelen `let` yerelini iki motor da yakalayamaz
+            if scope.pending.contains(name) && (scope.function_level == self.function_level || !scope.hoisted.contains(name)) {
+                before_declare = true;
+                break;
+            }
+        }
+        let top_pending = self.function_level == 0
+            && self.top_level.contains(name)
+            && !self.top_declared.contains(name)
+            && !self.known.contains(name);
+        if before_declare || top_pending {
+            self.error(&format!("'{}' tanımlanmadan önce kullanıldı", name), span);
+        }
+        None
+    }
+
+    /// Hiçbir kapsamda bildirilmeyen isim; atama için hata sayılır.
+    fn is_undeclared(&self, name: &str) -> bool {
+        !self.has_imports
+            && !self.known.contains(name)
+            && !self.top_level.contains(name)
+            && !self.scopes.iter().any(|s| s.names.contains_key(name) || s.pending.contains(name))
+    }
+
+    fn resolve_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Int(..) | Expr::Float(..) | Expr::String(..) | Expr::Bool(..) | Expr::Null(_) | Expr::Object { .. } => {}
+            Expr::Identifier(name, slot, span) => *slot = self.lookup(name, *span),
+            Expr::Assign { name, value, slot, span } => {
+                self.resolve_expr(value);
+                if self.is_undeclared(name) {
+                    self.error(&format!("Tanımlanmamış değişkene atama: '{}'", name), *span);
+                } else {
+                    *slot = self.lookup(name, *span);
+                }
+            }
+            Expr::BinaryOp { left, right, .. } => {
+                self.resolve_expr(left);
+                self.resolve_expr(right);
+            }
+            Expr::UnaryOp { expr, .. } | Expr::Await { expr, .. } => self.resolve_expr(expr),
+            Expr::Range { start, end, .. } => {
+                self.resolve_expr(start);
+                self.resolve_expr(end);
+            }
+            Expr::Call { func, args, .. } => {
+                self.resolve_expr(func);
+                self.resolve_exprs(args);
+            }
+            Expr::List(items, _) | Expr::Interpolation { parts: items, .. } => self.resolve_exprs(items),
+            Expr::Map(pairs, _) => {
+                for (k, v) in pairs {
+                    self.resolve_expr(k);
+                    self.resolve_expr(v);
+                }
+            }
+            Expr::Index { collection, index, .. } => {
+                self.resolve_expr(collection);
+                self.resolve_expr(index);
+            }
+            Expr::SetIndex { collection, index, value, .. } => {
+                self.resolve_expr(collection);
+                self.resolve_expr(index);
+                self.resolve_expr(value);
+            }
+            Expr::Get { object, .. } => self.resolve_expr(object),
+            Expr::Set { object, value, .. } => {
+                self.resolve_expr(object);
+                self.resolve_expr(value);
+            }
+            Expr::MethodCall { object, args, span, .. } => {
+                // `super` yalnızca üst sınıfı olan sınıfların metotlarında tanımlanır
+                let is_super = matches!(object.as_ref(), Expr::Identifier(name, _, _) if name == "super");
+                if is_super && !self.scopes.iter().any(|scope| scope.names.contains_key("super")) {
+                    self.error("super yalnızca alt sınıf metotlarında kullanılabilir", *span);
+                }
+                self.resolve_expr(object);
+                self.resolve_exprs(args);
+            }
+            Expr::New { args, .. } => self.resolve_exprs(args),
+            Expr::Lambda { params, body, span } => {
+                let loops = self.enter_function(params, &[], *span);
+                self.resolve_expr(body);
+                self.exit_function(loops);
+            }
+        }
+    }
+
+    fn resolve_exprs(&mut self, exprs: &mut [Expr]) {
+        for expr in exprs {
+            self.resolve_expr(expr);
+        }
+    }
+}
+
+/// Bir deyim listesinin doğrudan bildirdiği isimler (iç bloklar hariç).
+fn declared_names(stmts: &[Stmt]) -> HashSet<String> {
+    fn name_of(stmt: &Stmt) -> Option<&String> {
+        match stmt {
+            Stmt::Let { name, .. } | Stmt::Func { name, .. } | Stmt::AsyncFunc { name, .. } | Stmt::Class { name, .. } => Some(name),
+            Stmt::Export { decl, .. } => name_of(decl),
+            _ => None,
+        }
+    }
+    stmts.iter().filter_map(name_of).cloned().collect()
+}
+
+fn declared_functions(stmts: &[Stmt]) -> HashSet<String> {
+    stmts
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Stmt::Func { name, .. } | Stmt::AsyncFunc { name, .. } => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
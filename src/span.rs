@@ -0,0 +1,76 @@
+// src/span.rs
+// NovaLang için kaynak konumları: byte aralıkları ve kaynak dosya tablosu
+
+/// Bir kaynak dosyadaki `[start, end)` byte aralığı.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub file_id: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(file_id: usize, start: usize, end: usize) -> Self {
+        Span { file_id, start, end }
+    }
+
+    /// Bu aralığın başından `other`'ın sonuna kadar uzanan aralık.
+    pub fn to(self, other: Span) -> Span {
+        Span { file_id: self.file_id, start: self.start.min(other.start), end: self.end.max(other.end) }
+    }
+}
+
+pub struct SourceFile {
+    pub name: String,
+    pub src: String,
+    line_starts: Vec<usize>,
+}
+
+/// Yüklenen tüm kaynak dosyaları; `Span::file_id` bu tablodaki sıradır.
+#[derive(Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        SourceMap { files: Vec::new() }
+    }
+
+    pub fn add(&mut self, name: &str, src: &str) -> usize {
+        let mut line_starts = vec![0];
+        line_starts.extend(src.match_indices('\n').map(|(i, _)| i + 1));
+        self.files.push(SourceFile { name: name.to_string(), src: src.to_string(), line_starts });
+        self.files.len() - 1
+    }
+
+    pub fn file(&self, file_id: usize) -> Option<&SourceFile> {
+        self.files.get(file_id)
+    }
+
+    /// Byte konumunu 1 tabanlı (satır, sütun) çiftine çevirir; sütun karakter sayısıdır.
+    pub fn line_col(&self, file_id: usize, offset: usize) -> (usize, usize) {
+        let file = match self.file(file_id) {
+            Some(f) => f,
+            None => return (0, 0),
+        };
+        let line = file.line_starts.partition_point(|&start| start <= offset).max(1);
+        let line_start = file.line_starts[line - 1];
+        let end = offset.min(file.src.len());
+        let col = file.src.get(line_start..end).map_or(1, |s| s.chars().count() + 1);
+        (line, col)
+    }
+
+    /// 1 tabanlı satırın metni (satır sonu hariç).
+    pub fn line_text(&self, file_id: usize, line: usize) -> Option<&str> {
+        let file = self.file(file_id)?;
+        let start = *file.line_starts.get(line.checked_sub(1)?)?;
+        let end = file.line_starts.get(line).map_or(file.src.len(), |&next| next - 1);
+        file.src.get(start..end).map(|s| s.trim_end_matches('\r'))
+    }
+
+    /// Aralığın kaynak koddaki metni.
+    pub fn slice(&self, span: Span) -> Option<&str> {
+        self.file(span.file_id)?.src.get(span.start..span.end)
+    }
+}
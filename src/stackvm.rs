@@ -0,0 +1,669 @@
+// src/stackvm.rs
+// NovaLang için bytecode yorumlayan yığın makinesi
+
+use crate::arith;
+use crate::bytecode::{Constant, Function, Module, Op};
+use crate::error::{stack_frame, NovaError, NovaErrorKind};
+use crate::novac;
+use crate::runtime::{self, Iter, Runtime};
+use crate::span::{SourceMap, Span};
+use crate::vm::{ClassInfo, Object, Value};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+use std::rc::Rc;
+
+
+/// Çalışma zamanındaki fonksiyon değeri: modüldeki fonksiyon ve yakaladığı değişkenler.
+pub struct Closure {
+    pub module: Rc<Module>,
+    pub function: usize,
+    pub upvalues: Vec<Rc<RefCell<Upvalue>>>,
+}
+
+impl Closure {
+    fn function(&self) -> &Function {
+        &self.module.functions[self.function]
+    }
+}
+
+impl fmt::Debug for Closure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<fn {}>", self.function().name)
+    }
+}
+
+/// Yakalanan değişken: kapsamı sürerken yığındaki yuvayı gösterir, kapsam
+/// bitince değeri kendi içine alır.
+#[derive(Debug)]
+pub enum Upvalue {
+    Open(usize),
+    Closed(Value),
+}
+
+struct Frame {
+    closure: Rc<Closure>,
+    ip: usize,
+    base: usize,
+}
+
+struct Handler {
+    // Kurulduğu andaki çerçeve sayısı ve yığın yüksekliği
+    frames: usize,
+    stack: usize,
+    target: usize,
+}
+
+pub struct StackVM {
+    pub globals: HashMap<String, Value>,
+    pub runtime: Runtime,
+    pub export_table: HashMap<String, Value>,
+    pub sources: SourceMap,
+    stack: Vec<Value>,
+    frames: Vec<Frame>,
+    handlers: Vec<Handler>,
+    open_upvalues: Vec<Rc<RefCell<Upvalue>>>,
+}
+
+impl StackVM {
+    pub fn new() -> Self {
+        StackVM {
+            globals: HashMap::new(),
+            runtime: Runtime::new(),
+            export_table: HashMap::new(),
+            sources: SourceMap::new(),
+            stack: Vec::new(),
+            frames: Vec::new(),
+            handlers: Vec::new(),
+            open_upvalues: Vec::new(),
+        }
+    }
+
+    pub fn known_globals(&self) -> HashSet<String> {
+        self.globals.keys().chain(self.runtime.builtins.keys()).cloned().collect()
+    }
+
+    /// Modülün üst düzey kodunu çalıştırır; hata olursa makine temiz duruma döner.
+    pub fn run(&mut self, module: Module) -> Result<(), NovaError> {
+        let result = self.run_module(Rc::new(module));
+        if result.is_err() {
+            self.stack.clear();
+            self.frames.clear();
+            self.handlers.clear();
+            self.open_upvalues.clear();
+        }
+        result
+    }
+
+    fn run_module(&mut self, module: Rc<Module>) -> Result<(), NovaError> {
+        let exports = module.exports.clone();
+        let script = Value::Closure(Rc::new(Closure { module, function: 0, upvalues: Vec::new() }));
+        self.call_sync(script, None, Vec::new(), Span::default())?;
+        for name in exports {
+            if let Some(val) = self.globals.get(&name) {
+                self.export_table.insert(name, val.clone());
+            }
+        }
+        Ok(())
+    }
+
+    // --- Çağrılar ---
+
+    /// Değeri çağırır ve dönüşünü bekler. `receiver` verilirse 0. yuvaya o konur.
+    fn call_sync(&mut self, callee: Value, receiver: Option<Value>, args: Vec<Value>, span: Span) -> Result<Value, NovaError> {
+        let depth = self.frames.len();
+        let argc = args.len();
+        self.stack.push(receiver.unwrap_or_else(|| callee.clone()));
+        self.stack.extend(args);
+        match callee {
+            Value::Closure(closure) => self.push_frame(closure, argc, span)?,
+            _ => self.call_value(callee, argc, span)?,
+        }
+        if self.frames.len() > depth {
+            self.execute(depth)?;
+        }
+        Ok(self.stack.pop().unwrap_or(Value::Null))
+    }
+
+    /// Yığında `[çağrılan, argümanlar...]` duran çağrıyı başlatır. Closure için yeni
+    /// çerçeve açılır; sınıf ve hatalar hemen sonuçlanır.
+    fn call_value(&mut self, callee: Value, argc: usize, span: Span) -> Result<(), NovaError> {
+        match callee {
+            Value::Closure(closure) => self.push_frame(closure, argc, span),
+            Value::Class(class) => {
+                let args = self.stack.split_off(self.stack.len() - argc);
+                self.stack.pop();
+                let object = self.instantiate(&class, args, span)?;
+                self.stack.push(object);
+                Ok(())
+            }
+            _ => Err(runtime_error("Fonksiyon çağrısı geçersiz", span)),
+        }
+    }
+
+    fn push_frame(&mut self, closure: Rc<Closure>, argc: usize, span: Span) -> Result<(), NovaError> {
+        if closure.function().arity as usize != argc {
+            return Err(runtime_error("Fonksiyon parametre sayısı uyuşmuyor", span));
+        }
+        if self.frames.len() >= runtime::FRAMES_MAX {
+            return Err(runtime_error(runtime::STACK_OVERFLOW, span));
+        }
+        let base = self.stack.len() - argc - 1;
+        self.frames.push(Frame { closure, ip: 0, base });
+        Ok(())
+    }
+
+    /// Yığında `[alıcı, argümanlar...]` duran metot çağrısını `class` sınıfından başlayarak çözer.
+    fn invoke(&mut self, class: &Rc<ClassInfo>, method: &str, argc: usize, span: Span) -> Result<(), NovaError> {
+        match class.find_method(method) {
+            Some((_, Value::Closure(closure))) => self.push_frame(closure, argc, span),
+            _ => {
+                let message = format!("'{}' sınıfında '{}' metodu yok", class.name, method);
+                Err(runtime_error(&message, span))
+            }
+        }
+    }
+
+    /// Yeni nesne: alanlar kök sınıftan başlayarak ilklendirilir, ardından varsa `init` çağrılır.
+    fn instantiate(&mut self, class: &Rc<ClassInfo>, args: Vec<Value>, span: Span) -> Result<Value, NovaError> {
+        let mut fields = HashMap::new();
+        for (name, init) in class.field_inits() {
+            let val = self.call_sync(init, None, Vec::new(), span)?;
+            fields.insert(name, val);
+        }
+        let object = Value::Object(Rc::new(RefCell::new(Object { class: class.clone(), fields })));
+        match class.find_method("init") {
+            Some((_, init)) => {
+                self.call_sync(init, Some(object.clone()), args, span)?;
+            }
+            None if !args.is_empty() => {
+                let message = format!("'{}' sınıfının init yapıcısı yok, argüman verilemez", class.name);
+                return Err(runtime_error(&message, span));
+            }
+            None => {}
+        }
+        Ok(object)
+    }
+
+    // --- Upvalue'lar ---
+
+    fn capture_upvalue(&mut self, slot: usize) -> Rc<RefCell<Upvalue>> {
+        let existing = self.open_upvalues.iter().find(|u| matches!(*u.borrow(), Upvalue::Open(i) if i == slot));
+        if let Some(upvalue) = existing {
+            return upvalue.clone();
+        }
+        let upvalue = Rc::new(RefCell::new(Upvalue::Open(slot)));
+        self.open_upvalues.push(upvalue.clone());
+        upvalue
+    }
+
+    /// `from` ve üstündeki yuvaları gösteren upvalue'ları kapatır.
+    fn close_upvalues(&mut self, from: usize) {
+        let stack = &self.stack;
+        self.open_upvalues.retain(|upvalue| {
+            let slot = match *upvalue.borrow() {
+                Upvalue::Open(slot) => slot,
+                Upvalue::Closed(_) => return false,
+            };
+            if slot < from {
+                return true;
+            }
+            *upvalue.borrow_mut() = Upvalue::Closed(stack[slot].clone());
+            false
+        });
+    }
+
+    fn read_upvalue(&self, upvalue: &Rc<RefCell<Upvalue>>) -> Value {
+        match &*upvalue.borrow() {
+            Upvalue::Open(slot) => self.stack[*slot].clone(),
+            Upvalue::Closed(val) => val.clone(),
+        }
+    }
+
+    fn write_upvalue(&mut self, upvalue: &Rc<RefCell<Upvalue>>, val: Value) {
+        let mut upvalue = upvalue.borrow_mut();
+        match &mut *upvalue {
+            Upvalue::Open(slot) => self.stack[*slot] = val,
+            Upvalue::Closed(old) => *old = val,
+        }
+    }
+
+    // --- Yürütme döngüsü ---
+
+    /// Çerçeve sayısı `depth`'e inene kadar komut yürütür. Bu aralıkta kurulmuş bir
+    /// try varsa hata oraya yönlendirilir, yoksa çağırana iletilir.
+    fn execute(&mut self, depth: usize) -> Result<(), NovaError> {
+        while self.frames.len() > depth {
+            if let Err(e) = self.step() {
+                let e = self.trace(e);
+                match self.handlers.last() {
+                    Some(handler) if handler.frames > depth => {
+                        let handler = self.handlers.pop().unwrap();
+                        self.frames.truncate(handler.frames);
+                        self.close_upvalues(handler.stack);
+                        self.stack.truncate(handler.stack);
+                        self.stack.push(Value::Error(Rc::new(e)));
+                        self.frames.last_mut().unwrap().ip = handler.target;
+                    }
+                    _ => return Err(e),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Hatanın konumunu ve (henüz yoksa) çağrı yığınını açık çerçevelerden doldurur.
+    /// `.novac`'tan yüklenen kodda kaynak aralığı yoktur; konum satır tablosundan alınır.
+    fn trace(&self, mut e: NovaError) -> NovaError {
+        let frame = match self.frames.last() {
+            Some(frame) => frame,
+            None => return e,
+        };
+        let lines = &frame.closure.function().chunk.lines;
+        if e.line == 0 {
+            if let Some(&(line, col)) = frame.ip.checked_sub(1).and_then(|ip| lines.get(ip)) {
+                e.line = line as usize;
+                e.col = col as usize;
+            }
+        }
+        let mut e = e.locate(&self.sources);
+        if !e.stack.is_empty() {
+            return e;
+        }
+        for (i, frame) in self.frames.iter().rev().enumerate() {
+            let (file_id, line) = match (i, e.span) {
+                (0, Some(span)) => (span.file_id, e.line),
+                _ => self.frame_line(frame),
+            };
+            e.stack.push(stack_frame(&frame.closure.function().name, &self.sources, file_id, line));
+        }
+        e
+    }
+
+    /// Çerçevede en son çalıştırılan komutun dosyası ve satırı.
+    fn frame_line(&self, frame: &Frame) -> (usize, usize) {
+        let chunk = &frame.closure.function().chunk;
+        let ip = frame.ip.saturating_sub(1);
+        let span = chunk.spans.get(ip).copied().unwrap_or_default();
+        let line = match chunk.lines.get(ip) {
+            Some(&(line, _)) => line as usize,
+            None => self.sources.line_col(span.file_id, span.start).0,
+        };
+        (span.file_id, line)
+    }
+
+    fn pop(&mut self) -> Value {
+        self.stack.pop().expect("yığın boş")
+    }
+
+    fn peek(&self, distance: usize) -> &Value {
+        &self.stack[self.stack.len() - 1 - distance]
+    }
+
+    fn step(&mut self) -> Result<(), NovaError> {
+        let frame = self.frames.last_mut().expect("çerçeve yok");
+        let closure = frame.closure.clone();
+        let ip = frame.ip;
+        frame.ip += 1;
+        let base = frame.base;
+        let chunk = &closure.function().chunk;
+        let op = chunk.code[ip];
+        let span = chunk.spans[ip];
+        let name = |index: u16| match &chunk.constants[index as usize] {
+            Constant::String(s) => s.as_str(),
+            c => unreachable!("isim sabiti bekleniyordu: {:?}", c),
+        };
+        match op {
+            Op::Constant(index) => {
+                let val = match &chunk.constants[index as usize] {
+                    Constant::Int(i) => Value::Int(*i),
+                    Constant::Float(f) => Value::Float(*f),
+                    Constant::String(s) => Value::String(s.clone()),
+                    Constant::Function(_) => unreachable!("fonksiyon sabiti Closure ile yüklenir"),
+                };
+                self.stack.push(val);
+            }
+            Op::Null => self.stack.push(Value::Null),
+            Op::True => self.stack.push(Value::Bool(true)),
+            Op::False => self.stack.push(Value::Bool(false)),
+            Op::Pop => {
+                self.pop();
+            }
+            Op::Dup => self.stack.push(self.peek(0).clone()),
+            Op::GetLocal(slot) => self.stack.push(self.stack[base + slot as usize].clone()),
+            Op::SetLocal(slot) => self.stack[base + slot as usize] = self.peek(0).clone(),
+            Op::GetUpvalue(index) => {
+                let val = self.read_upvalue(&closure.upvalues[index as usize]);
+                self.stack.push(val);
+            }
+            Op::SetUpvalue(index) => {
+                let val = self.peek(0).clone();
+                self.write_upvalue(&closure.upvalues[index as usize], val);
+            }
+            Op::CloseUpvalue => {
+                self.close_upvalues(self.stack.len() - 1);
+                self.pop();
+            }
+            Op::DefineGlobal(index) => {
+                let val = self.pop();
+                self.globals.insert(name(index).to_string(), val);
+            }
+            Op::GetGlobal(index) => match self.globals.get(name(index)) {
+                Some(val) => self.stack.push(val.clone()),
+                None => return Err(runtime_error(&format!("Tanımlanmamış değişken: {}", name(index)), span)),
+            },
+            Op::SetGlobal(index) => {
+                let val = self.peek(0).clone();
+                match self.globals.get_mut(name(index)) {
+                    Some(slot) => *slot = val,
+                    None => return Err(runtime_error(&format!("Tanımlanmamış değişken: {}", name(index)), span)),
+                }
+            }
+            Op::GetField(index) => {
+                let object = self.pop();
+                let val = runtime::get_field(&object, name(index)).map_err(|msg| runtime_error(&msg, span))?;
+                self.stack.push(val);
+            }
+            Op::SetField(index) => {
+                let val = self.pop();
+                let object = self.pop();
+                runtime::set_field(&object, name(index), val.clone()).map_err(|msg| runtime_error(&msg, span))?;
+                self.stack.push(val);
+            }
+            Op::GetIndex => {
+                let index = self.pop();
+                let collection = self.pop();
+                let val = runtime::get_index(&collection, &index).map_err(|msg| runtime_error(&msg, span))?;
+                self.stack.push(val);
+            }
+            Op::SetIndex => {
+                let val = self.pop();
+                let index = self.pop();
+                let collection = self.pop();
+                runtime::set_index(&collection, &index, val.clone()).map_err(|msg| runtime_error(&msg, span))?;
+                self.stack.push(val);
+            }
+            Op::Equal
+            | Op::NotEqual
+            | Op::Add
+            | Op::Subtract
+            | Op::Multiply
+            | Op::Divide
+            | Op::Modulo
+            | Op::Less
+            | Op::Greater
+            | Op::LessEqual
+            | Op::GreaterEqual => {
+                let right = self.pop();
+                let left = self.pop();
+                let val = arith::binary(binary_symbol(op), &left, &right).map_err(|e| e.with_span(span))?;
+                self.stack.push(val);
+            }
+            Op::Negate | Op::Not => {
+                let val = self.pop();
+                let symbol = if op == Op::Negate { "-" } else { "!" };
+                self.stack.push(arith::unary(symbol, &val).map_err(|e| e.with_span(span))?);
+            }
+            Op::Jump(target) => self.frames.last_mut().unwrap().ip = target as usize,
+            Op::Iter => {
+                let val = self.pop();
+                let iter = self.iterate(val, span)?;
+                self.stack.push(Value::Iterator(Rc::new(RefCell::new(iter))));
+            }
+            Op::IterNext(target) | Op::IterNextPair(target) => {
+                let pair = matches!(op, Op::IterNextPair(_));
+                let iter = match self.peek(0) {
+                    Value::Iterator(iter) => iter.clone(),
+                    _ => return Err(runtime_error("Yığının tepesinde yineleyici bekleniyordu", span)),
+                };
+                match self.iter_next(&iter, pair, span)? {
+                    Some((first, second)) => {
+                        self.stack.push(first);
+                        if pair {
+                            self.stack.push(second);
+                        }
+                    }
+                    None => self.frames.last_mut().unwrap().ip = target as usize,
+                }
+            }
+            Op::Range | Op::RangeInclusive => {
+                let end = self.pop();
+                let start = self.pop();
+                let range = runtime::make_range(&start, &end, op == Op::RangeInclusive).map_err(|msg| runtime_error(&msg, span))?;
+                self.stack.push(range);
+            }
+            Op::JumpIfFalse(target) => {
+                if !self.pop().is_truthy() {
+                    self.frames.last_mut().unwrap().ip = target as usize;
+                }
+            }
+            Op::Call(argc) => {
+                let callee = self.peek(argc as usize).clone();
+                self.call_value(callee, argc as usize, span)?;
+            }
+            Op::CallGlobal(index, argc) => {
+                let argc = argc as usize;
+                let name = name(index);
+                if let Some(callee) = self.globals.get(name).cloned() {
+                    let at = self.stack.len() - argc;
+                    self.stack.insert(at, callee.clone());
+                    self.call_value(callee, argc, span)?;
+                } else if let Some(builtin) = self.runtime.builtins.get(name).copied() {
+                    let args = self.stack.split_off(self.stack.len() - argc);
+                    self.stack.push(builtin(args));
+                } else {
+                    // Ağaç yorumlayıcı gibi yalnızca çağrının başındaki ismin altı çizilir
+                    let end = span.start + name.len();
+                    let callee = if end <= span.end { Span::new(span.file_id, span.start, end) } else { span };
+                    return Err(runtime_error(&format!("Tanımlanmamış değişken: {}", name), callee));
+                }
+            }
+            Op::Invoke(index, argc) => {
+                let argc = argc as usize;
+                let obj = match self.peek(argc) {
+                    Value::Object(obj) => obj.clone(),
+                    _ => return Err(runtime_error("Metot çağrısı yalnızca nesnelerde yapılabilir", span)),
+                };
+                // Fonksiyon tutan bir alan metotlardan önce gelir
+                let field = obj.borrow().fields.get(name(index)).cloned();
+                if let Some(callee) = field {
+                    let at = self.stack.len() - 1 - argc;
+                    self.stack[at] = callee.clone();
+                    self.call_value(callee, argc, span)?;
+                } else {
+                    let class = obj.borrow().class.clone();
+                    self.invoke(&class, name(index), argc, span)?;
+                }
+            }
+            Op::SuperInvoke(method, argc) => {
+                let base = match self.pop() {
+                    Value::Class(base) => base,
+                    _ => return Err(runtime_error("super yalnızca alt sınıf metotlarında kullanılabilir", span)),
+                };
+                self.invoke(&base, name(method), argc as usize, span)?;
+            }
+            Op::Closure(index) => {
+                let function = match chunk.constants[index as usize] {
+                    Constant::Function(f) => f as usize,
+                    ref c => unreachable!("fonksiyon sabiti bekleniyordu: {:?}", c),
+                };
+                let descs = closure.module.functions[function].upvalues.clone();
+                let mut upvalues = Vec::with_capacity(descs.len());
+                for desc in descs {
+                    if desc.is_local {
+                        upvalues.push(self.capture_upvalue(base + desc.index as usize));
+                    } else {
+                        upvalues.push(closure.upvalues[desc.index as usize].clone());
+                    }
+                }
+                let new = Closure { module: closure.module.clone(), function, upvalues };
+                self.stack.push(Value::Closure(Rc::new(new)));
+            }
+            Op::Throw => {
+                let val = self.pop();
+                return Err(runtime::thrown(val, span));
+            }
+            Op::Return => {
+                let result = self.pop();
+                let frames = self.frames.len();
+                // Dönen çerçevede kurulmuş try'lar da kapanır
+                while self.handlers.last().is_some_and(|h| h.frames >= frames) {
+                    self.handlers.pop();
+                }
+                self.close_upvalues(base);
+                self.stack.truncate(base);
+                self.frames.pop();
+                self.stack.push(result);
+            }
+            Op::BuildList(count) => {
+                let items = self.stack.split_off(self.stack.len() - count as usize);
+                self.stack.push(Value::list(items));
+            }
+            Op::BuildMap(count) => {
+                let flat = self.stack.split_off(self.stack.len() - 2 * count as usize);
+                let mut map = HashMap::new();
+                let mut iter = flat.into_iter();
+                while let (Some(key), Some(val)) = (iter.next(), iter.next()) {
+                    match key {
+                        Value::String(key) => map.insert(key, val),
+                        _ => return Err(runtime_error("Map anahtarı string olmalı", span)),
+                    };
+                }
+                self.stack.push(Value::map(map));
+            }
+            Op::Interpolate(count) => {
+                let parts = self.stack.split_off(self.stack.len() - count as usize);
+                let text: String = parts.iter().map(runtime::format_value).collect();
+                self.stack.push(Value::String(text));
+            }
+            Op::Class(index) => {
+                let class = ClassInfo { name: name(index).to_string(), base: None, fields: Vec::new(), methods: HashMap::new() };
+                self.stack.push(Value::Class(Rc::new(class)));
+            }
+            Op::Inherit(index) => {
+                let base = match self.peek(1) {
+                    Value::Class(base) => base.clone(),
+                    _ => return Err(runtime_error(&runtime::not_a_class(name(index)), span)),
+                };
+                self.current_class(span)?.base = Some(base);
+            }
+            Op::Method(index) => {
+                let method = self.pop();
+                self.current_class(span)?.methods.insert(name(index).to_string(), method);
+            }
+            Op::Field(index) => {
+                let init = self.pop();
+                self.current_class(span)?.fields.push((name(index).to_string(), init));
+            }
+            Op::Import(index) => self.import(name(index), span)?,
+            Op::PushHandler(target) => {
+                let handler = Handler { frames: self.frames.len(), stack: self.stack.len(), target: target as usize };
+                self.handlers.push(handler);
+            }
+            Op::PopHandler => {
+                self.handlers.pop();
+            }
+        }
+        Ok(())
+    }
+
+    /// for-in için yineleyici; nesnelerde ağaç yorumlayıcıyla aynı `iter()`/`next()` kuralı geçerlidir.
+    fn iterate(&mut self, val: Value, span: Span) -> Result<Iter, NovaError> {
+        let class = match &val {
+            Value::Object(obj) => obj.borrow().class.clone(),
+            _ => return Iter::new(&val).map_err(|msg| runtime_error(&msg, span)),
+        };
+        if let Some((_, method)) = class.find_method("iter") {
+            let iterator = self.call_sync(method, Some(val), Vec::new(), span)?;
+            return match iterator {
+                Value::Object(_) => Ok(Iter::Object(iterator)),
+                other => Iter::new(&other).map_err(|msg| runtime_error(&msg, span)),
+            };
+        }
+        if class.find_method("next").is_some() {
+            return Ok(Iter::Object(val));
+        }
+        let message = format!("'{}' sınıfı gezilemez: iter() ya da next() metodu yok", class.name);
+        Err(runtime_error(&message, span))
+    }
+
+    fn iter_next(&mut self, iter: &Rc<RefCell<Iter>>, pair: bool, span: Span) -> Result<Option<(Value, Value)>, NovaError> {
+        let object = match &mut *iter.borrow_mut() {
+            Iter::Object(object) => object.clone(),
+            iter => return Ok(iter.next(pair)),
+        };
+        if pair {
+            return Err(runtime_error("İki değişkenli for döngüsü nesne yineleyicileriyle kullanılamaz", span));
+        }
+        let class = match &object {
+            Value::Object(obj) => obj.borrow().class.clone(),
+            _ => unreachable!("nesne yineleyicisi nesne tutar"),
+        };
+        let method = match class.find_method("next") {
+            Some((_, method)) => method,
+            None => return Err(runtime_error(&format!("'{}' sınıfında 'next' metodu yok", class.name), span)),
+        };
+        match self.call_sync(method, Some(object), Vec::new(), span)? {
+            Value::Null => Ok(None),
+            item => Ok(Some((item, Value::Null))),
+        }
+    }
+
+    /// Gövdesi kurulmakta olan sınıf; tanımı bitene kadar ona başka referans yoktur.
+    fn current_class(&mut self, span: Span) -> Result<&mut ClassInfo, NovaError> {
+        match self.stack.last_mut() {
+            Some(Value::Class(class)) => Rc::get_mut(class).ok_or_else(|| runtime_error("Sınıf tanımı tamamlandıktan sonra değiştirilemez", span)),
+            _ => Err(runtime_error("Yığının tepesinde sınıf bekleniyordu", span)),
+        }
+    }
+
+    /// Basit modül yükleyici: güncel bir 'moduladi.novac' varsa onu, yoksa 'moduladi.nova'
+    /// dosyasını derleyip aynı globallerde çalıştırır.
+    fn import(&mut self, name: &str, span: Span) -> Result<(), NovaError> {
+        let filename = format!("{}.nova", name);
+        let compiled = format!("{}.novac", name);
+        if novac::is_fresh(Path::new(&compiled), Path::new(&filename)) {
+            let module = novac::load(Path::new(&compiled), &mut self.sources)
+                .map_err(|msg| runtime_error(&format!("{}: {}", compiled, msg), span))?;
+            return self.run_module(Rc::new(module));
+        }
+        let code = match std::fs::read_to_string(&filename) {
+            Ok(code) => code,
+            Err(_) => return Err(runtime_error("Modül dosyası bulunamadı", span)),
+        };
+        let file_id = self.sources.add(&filename, &code);
+        let mut lexer = crate::lexer::Lexer::new(&code).with_file(file_id);
+        let tokens = lexer.tokenize()?;
+        let mut parser = crate::parser::Parser::new(tokens).with_keywords(lexer.keyword_set());
+        // Modüldeki ilk hata içe aktarma hatası olarak raporlanır
+        let mut program = parser.parse_program().map_err(|mut errors| errors.remove(0))?;
+        crate::semantic::Resolver::new(self.known_globals())
+            .resolve(&mut program)
+            .map_err(|mut errors| errors.remove(0))?;
+        crate::semantic::SemanticAnalyzer::new().analyze(&program)?;
+        let module = crate::compiler::Compiler::new().compile(&program)?;
+        self.run_module(Rc::new(module))
+    }
+}
+
+/// İkili işlem komutunun `arith::binary`'nin beklediği operatör yazımı.
+fn binary_symbol(op: Op) -> &'static str {
+    match op {
+        Op::Equal => "==",
+        Op::NotEqual => "!=",
+        Op::Add => "+",
+        Op::Subtract => "-",
+        Op::Multiply => "*",
+        Op::Divide => "/",
+        Op::Modulo => "%",
+        Op::Less => "<",
+        Op::Greater => ">",
+        Op::LessEqual => "<=",
+        _ => ">=",
+    }
+}
+
+fn runtime_error(message: &str, span: Span) -> NovaError {
+    NovaError::at(NovaErrorKind::RuntimeError, message, span)
+}
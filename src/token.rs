@@ -1,27 +1,90 @@
-#[derive(Debug, Clone, PartialEq)]
-pub enum TokenKind {
-    // Anahtar kelimeler
-    Let, Func, If, Else, While, Return, True, False, Null,
-    // Semboller
-    Identifier(String),
-    Int(i64),
-    Float(f64),
-    String(String),
-    // Operatörler ve parantezler
-    LParen, RParen, LBracket, RBracket, LBrace, RBrace, Comma, Semicolon,
-    Assign, Plus, Minus, Star, Slash, Percent,
-    Eq, Neq, Lt, Gt, Le, Ge,
-    Colon,
-    // Yorum ve dosya sonu
-    Comment(String),
-    Eof,
-    Import,
-    Arrow, // =>
-}
-
-#[derive(Debug, Clone)]
-pub struct Token {
-    pub kind: TokenKind,
-    pub line: usize,
-    pub col: usize,
-} 
\ No newline at end of file
+use crate::lexer::KeywordSet;
+use crate::span::Span;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    // Anahtar kelimeler
+    Let, Func, If, Else, While, For, In, Return, Break, Continue, True, False, Null,
+    Class, Extends, New, Import, Export, Async, Await, Try, Catch, Finally, Throw,
+    // Semboller
+    Identifier(String),
+    Int(i64),
+    Float(f64),
+    String(String),
+    Template(Vec<TemplatePart>), // "Merhaba ${isim}"
+    // Operatörler ve parantezler
+    LParen, RParen, LBracket, RBracket, LBrace, RBrace, Comma, Semicolon,
+    Assign, Plus, Minus, Star, Slash, Percent,
+    Eq, Neq, Lt, Gt, Le, Ge,
+    Not, And, Or,
+    Colon, Dot,
+    DotDot, DotDotEq, // .. ve ..=
+    Arrow, // =>
+    // Doc yorumu (`///`) ve dosya sonu; diğer yorumlar lexer'da atlanır
+    Comment(String),
+    Eof,
+}
+
+impl TokenKind {
+    /// Token'ın kaynak koddaki yazılışı; anahtar kelimeler seçili tabloya göre yazılır.
+    pub fn to_source(&self, keywords: KeywordSet) -> String {
+        if let Some(word) = keywords.spelling(self) {
+            return word.to_string();
+        }
+        let text = match self {
+            TokenKind::Identifier(name) => return name.clone(),
+            TokenKind::Int(i) => return i.to_string(),
+            TokenKind::Float(f) => return format!("{:?}", f),
+            TokenKind::String(s) => return format!("{:?}", s),
+            TokenKind::Template(_) => "\"${...}\"",
+            TokenKind::Comment(text) => return format!("//{}", text),
+            TokenKind::LParen => "(",
+            TokenKind::RParen => ")",
+            TokenKind::LBracket => "[",
+            TokenKind::RBracket => "]",
+            TokenKind::LBrace => "{",
+            TokenKind::RBrace => "}",
+            TokenKind::Comma => ",",
+            TokenKind::Semicolon => ";",
+            TokenKind::Assign => "=",
+            TokenKind::Plus => "+",
+            TokenKind::Minus => "-",
+            TokenKind::Star => "*",
+            TokenKind::Slash => "/",
+            TokenKind::Percent => "%",
+            TokenKind::Eq => "==",
+            TokenKind::Neq => "!=",
+            TokenKind::Lt => "<",
+            TokenKind::Gt => ">",
+            TokenKind::Le => "<=",
+            TokenKind::Ge => ">=",
+            TokenKind::Not => "!",
+            TokenKind::And => "&&",
+            TokenKind::Or => "||",
+            TokenKind::Colon => ":",
+            TokenKind::Dot => ".",
+            TokenKind::DotDot => "..",
+            TokenKind::DotDotEq => "..=",
+            TokenKind::Arrow => "=>",
+            TokenKind::Eof => "dosya sonu",
+            // Anahtar kelimeler yukarıda tablodan yazılır
+            _ => "?",
+        };
+        text.to_string()
+    }
+}
+
+/// İnterpolasyonlu string parçası: düz metin ya da `${...}` içindeki ifadenin tokenları.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplatePart {
+    Text(String),
+    Code(Vec<Token>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub line: usize,
+    pub col: usize, // 1 tabanlı, byte değil karakter sayısı
+    pub span: Span,
+}
@@ -1,225 +1,758 @@
-// src/vm.rs
-// NovaLang için kapsamlı yorumlayıcı (interpreter) ve VM
-
-use crate::ast::{Expr, Stmt, Program};
-use crate::runtime::Runtime;
-use crate::error::{NovaError, NovaErrorKind};
-use std::collections::HashMap;
-
-#[derive(Debug, Clone)]
-pub enum Value {
-    Int(i64),
-    Float(f64),
-    String(String),
-    Bool(bool),
-    Null,
-    Func {
-        params: Vec<String>,
-        body: Vec<Stmt>,
-        env: Env,
-    },
-    List(Vec<Value>),
-    Map(std::collections::HashMap<String, Value>),
-}
-
-pub type Env = HashMap<String, Value>;
-
-#[derive(Debug, Clone)]
-pub struct ClassInfo {
-    pub name: String,
-    pub base: Option<String>,
-    pub methods: HashMap<String, Vec<Stmt>>,
-}
-
-pub struct VM {
-    pub globals: Env,
-    pub runtime: Runtime,
-    pub class_table: HashMap<String, ClassInfo>,
-    pub export_table: HashMap<String, Value>,
-}
-
-impl VM {
-    pub fn new() -> Self {
-        VM {
-            globals: HashMap::new(),
-            runtime: Runtime::new(),
-            class_table: HashMap::new(),
-            export_table: HashMap::new(),
-        }
-    }
-
-    pub fn run(&mut self, program: &Program) -> Result<(), NovaError> {
-        for stmt in &program.body {
-            self.eval_stmt(stmt, &mut self.globals.clone())?;
-        }
-        Ok(())
-    }
-
-    fn eval_stmt(&mut self, stmt: &Stmt, env: &mut Env) -> Result<Option<Value>, NovaError> {
-        match stmt {
-            Stmt::TryCatch { try_block, err_name, catch_block, line, col } => {
-                let mut local_env = env.clone();
-                match self.eval_block(try_block, &mut local_env) {
-                    Ok(_) => Ok(None),
-                    Err(e) => {
-                        local_env.insert(err_name.clone(), Value::String(e.message));
-                        self.eval_block(catch_block, &mut local_env)?;
-                        Ok(None)
-                    }
-                }
-            }
-            Stmt::Import { name, line, col } => {
-                // Basit modül yükleyici: 'moduladi.nova' dosyasını yükle ve çalıştır
-                let filename = format!("{}.nova", name);
-                match std::fs::read_to_string(&filename) {
-                    Ok(code) => {
-                        // Lex, parse, semantic, run
-                        let mut lexer = crate::lexer::Lexer::new(&code);
-                        let mut tokens = Vec::new();
-                        loop {
-                            let tok = lexer.next_token();
-                            if let crate::token::TokenKind::Eof = tok.kind { break; }
-                            tokens.push(tok);
-                        }
-                        let mut parser = crate::parser::Parser::new(tokens);
-                        let program = parser.parse_program()?;
-                        let mut analyzer = crate::semantic::SemanticAnalyzer::new();
-                        analyzer.analyze(&program)?;
-                        self.run(&program)?;
-                        Ok(None)
-                    }
-                    Err(_) => Err(crate::error::NovaError::new(crate::error::NovaErrorKind::RuntimeError, "Modül dosyası bulunamadı", *line, *col, None)),
-                }
-            }
-            Stmt::Class { name, base, methods, line, col } => {
-                // Sınıf tanımını global tabloya kaydet
-                // ...
-                Ok(None)
-            }
-            Stmt::Export { name, value, line, col } => {
-                // Export edilen sembolü global export tablosuna kaydet
-                // ...
-                Ok(None)
-            }
-            Stmt::AsyncFunc { name, params, body, line, col } => {
-                // Async fonksiyonu kaydet
-                // ...
-                Ok(None)
-            }
-            Stmt::TryCatchFinally { try_block, err_name, catch_block, finally_block, line, col } => {
-                // Try, catch ve finally bloklarını çalıştır
-                // ...
-                Ok(None)
-            }
-            _ => Ok(None),
-        }
-    }
-
-    fn eval_expr(&mut self, expr: &Expr, env: &mut Env) -> Result<Value, NovaError> {
-        match expr {
-            Expr::List(items, line, col) => {
-                let mut vals = Vec::new();
-                for item in items {
-                    vals.push(self.eval_expr(item, env)?);
-                }
-                Ok(Value::List(vals))
-            }
-            Expr::Map(pairs, line, col) => {
-                let mut map = std::collections::HashMap::new();
-                for (k, v) in pairs {
-                    let key_val = self.eval_expr(k, env)?;
-                    let val_val = self.eval_expr(v, env)?;
-                    if let Value::String(s) = key_val {
-                        map.insert(s, val_val);
-                    } else {
-                        return Err(NovaError::new(NovaErrorKind::RuntimeError, "Map anahtarı string olmalı", *line, *col, None));
-                    }
-                }
-                Ok(Value::Map(map))
-            }
-            Expr::Index { collection, index, line, col } => {
-                let coll_val = self.eval_expr(collection, env)?;
-                let idx_val = self.eval_expr(index, env)?;
-                match (coll_val, idx_val) {
-                    (Value::List(list), Value::Int(i)) => {
-                        let idx = i as usize;
-                        if idx < list.len() {
-                            Ok(list[idx].clone())
-                        } else {
-                            Err(NovaError::new(NovaErrorKind::RuntimeError, "Liste indeksi geçersiz", *line, *col, None))
-                        }
-                    }
-                    (Value::Map(map), Value::String(s)) => {
-                        if let Some(val) = map.get(&s) {
-                            Ok(val.clone())
-                        } else {
-                            Err(NovaError::new(NovaErrorKind::RuntimeError, "Map anahtarı bulunamadı", *line, *col, None))
-                        }
-                    }
-                    _ => Err(NovaError::new(NovaErrorKind::RuntimeError, "Geçersiz index işlemi", *line, *col, None)),
-                }
-            }
-            Expr::Lambda { params, body, line, col } => {
-                Ok(Value::Func {
-                    params: params.clone(),
-                    body: vec![Stmt::Expr(*body.clone())],
-                    env: env.clone(),
-                })
-            }
-            Expr::Call { func, args, line, col } => {
-                let func_val = self.eval_expr(func, env)?;
-                let mut arg_vals = Vec::new();
-                for arg in args {
-                    arg_vals.push(self.eval_expr(arg, env)?);
-                }
-                match func_val {
-                    Value::Func { params, body, mut env: closure_env } => {
-                        if params.len() != arg_vals.len() {
-                            return Err(NovaError::new(NovaErrorKind::RuntimeError, "Fonksiyon parametre sayısı uyuşmuyor", *line, *col, None));
-                        }
-                        for (i, param) in params.iter().enumerate() {
-                            closure_env.insert(param.clone(), arg_vals[i].clone());
-                        }
-                        let mut result = Value::Null;
-                        for stmt in &body {
-                            if let Some(val) = self.eval_stmt(stmt, &mut closure_env)? {
-                                result = val;
-                                break;
-                            }
-                        }
-                        Ok(result)
-                    }
-                    _ => Err(NovaError::new(NovaErrorKind::RuntimeError, "Fonksiyon çağrısı geçersiz", *line, *col, None)),
-                }
-            }
-            Expr::Object { class_name, fields } => {
-                // Nesne oluşturma
-                // ...
-                Ok(Value::Null)
-            }
-            Expr::MethodCall { object, method, args, line, col } => {
-                // Metot çağrısı
-                // ...
-                Ok(Value::Null)
-            }
-            Expr::New { class_name, args, line, col } => {
-                // new ile nesne oluşturma
-                // ...
-                Ok(Value::Null)
-            }
-            Expr::Await { expr, line, col } => {
-                // await ile async task bekleme
-                // ...
-                Ok(Value::Null)
-            }
-            _ => Ok(Value::Null),
-        }
-    }
-
-    fn eval_block(&mut self, block: &Vec<Stmt>, env: &mut Env) -> Result<Option<Value>, NovaError> {
-        for stmt in block {
-            self.eval_stmt(stmt, env)?;
-        }
-        Ok(None)
-    }
-} 
\ No newline at end of file
+// src/vm.rs
+// NovaLang için kapsamlı yorumlayıcı (interpreter) ve VM
+
+use crate::ast::{CatchClause, Expr, Stmt, Program, Slot};
+use crate::arith;
+use crate::runtime::{self, Runtime};
+use crate::error::{stack_frame, NovaError, NovaErrorKind};
+use crate::span::{SourceMap, Span};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    String(String),
+    Bool(bool),
+    Null,
+    // Gövde paylaşılır; fonksiyon değerini kopyalamak AST'yi kopyalamaz
+    Func {
+        // Çağrı yığınında görünen ad; lambdalar için `<lambda>`
+        name: String,
+        params: Vec<String>,
+        body: Rc<Vec<Stmt>>,
+        env: Env,
+    },
+    // Liste ve map içerikleri paylaşılır; kopyalanan değer aynı veriyi gösterir
+    List(Rc<RefCell<Vec<Value>>>),
+    Map(Rc<RefCell<HashMap<String, Value>>>),
+    // Sınıfın kendisi; çağrıldığında yeni nesne üretir: `Dog("Rex")`
+    Class(Rc<ClassInfo>),
+    // Nesneler paylaşılır: `this` üzerinden yapılan değişiklik tüm referanslarda görünür
+    Object(Rc<RefCell<Object>>),
+    // `start..end` aralığı; üçüncü alan `..=` ile yazılıp bitişin dahil olduğunu söyler
+    Range(i64, i64, bool),
+    // for-in döngüsünün yığındaki durumu; Nova koduna görünmez
+    Iterator(Rc<RefCell<runtime::Iter>>),
+    // Bytecode yorumlayıcının fonksiyon değeri
+    Closure(Rc<crate::stackvm::Closure>),
+    // catch ile yakalanan hata; `message`, `kind`, `line`, `col` ve `stack` alanları okunur
+    Error(Rc<NovaError>),
+}
+
+impl Value {
+    /// Koşullarda yalnızca `false` ve `null` yanlış sayılır.
+    pub fn is_truthy(&self) -> bool {
+        !matches!(self, Value::Bool(false) | Value::Null)
+    }
+
+    pub fn list(items: Vec<Value>) -> Value {
+        Value::List(Rc::new(RefCell::new(items)))
+    }
+
+    pub fn map(entries: HashMap<String, Value>) -> Value {
+        Value::Map(Rc::new(RefCell::new(entries)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Object {
+    pub class: Rc<ClassInfo>,
+    pub fields: HashMap<String, Value>,
+}
+
+/// Sözcüksel kapsam zinciri: her blok ve çağrı üst kapsamı gösteren yeni bir kapsam açar.
+/// Kopyalar aynı kapsamı paylaşır, bu yüzden closure'lar değişkenleri referansla yakalar.
+#[derive(Clone, Default)]
+pub struct Env(Rc<RefCell<Scope>>);
+
+/// Değerler tanım sırasıyla tutulur; resolver'ın verdiği `Slot::index` bu sıradır.
+#[derive(Default)]
+pub struct Scope {
+    names: HashMap<String, usize>,
+    values: Vec<Value>,
+    parent: Option<Env>,
+}
+
+impl Env {
+    pub fn new() -> Env {
+        Env::default()
+    }
+
+    pub fn child(&self) -> Env {
+        Env(Rc::new(RefCell::new(Scope { parent: Some(self.clone()), ..Scope::default() })))
+    }
+
+    /// Bu kapsamda yeni bir değişken tanımlar (aynı kapsamdaki eskisinin yerine geçer).
+    pub fn define(&self, name: &str, val: Value) {
+        let mut scope = self.0.borrow_mut();
+        match scope.names.get(name) {
+            Some(&index) => scope.values[index] = val,
+            None => {
+                let index = scope.values.len();
+                scope.names.insert(name.to_string(), index);
+                scope.values.push(val);
+            }
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<Value> {
+        let scope = self.0.borrow();
+        match scope.names.get(name) {
+            Some(&index) => Some(scope.values[index].clone()),
+            None => scope.parent.as_ref()?.get(name),
+        }
+    }
+
+    /// Değişkeni tanımlandığı en yakın kapsamda günceller; bulunamazsa `false` döner.
+    pub fn assign(&self, name: &str, val: Value) -> bool {
+        let mut scope = self.0.borrow_mut();
+        if let Some(&index) = scope.names.get(name) {
+            scope.values[index] = val;
+            return true;
+        }
+        match &scope.parent {
+            Some(parent) => parent.assign(name, val),
+            None => false,
+        }
+    }
+
+    fn ancestor(&self, depth: usize) -> Option<Env> {
+        let mut env = self.clone();
+        for _ in 0..depth {
+            let parent = env.0.borrow().parent.clone()?;
+            env = parent;
+        }
+        Some(env)
+    }
+
+    /// Resolver'ın hesapladığı konumdan okur; isim araması yapılmaz.
+    pub fn get_at(&self, slot: Slot) -> Option<Value> {
+        self.ancestor(slot.depth)?.0.borrow().values.get(slot.index).cloned()
+    }
+
+    pub fn assign_at(&self, slot: Slot, val: Value) -> bool {
+        match self.ancestor(slot.depth) {
+            Some(env) => match env.0.borrow_mut().values.get_mut(slot.index) {
+                Some(target) => {
+                    *target = val;
+                    true
+                }
+                None => false,
+            },
+            None => false,
+        }
+    }
+
+    /// Yalnızca bu kapsamda tanımlı isimler.
+    pub fn names(&self) -> Vec<String> {
+        self.0.borrow().names.keys().cloned().collect()
+    }
+}
+
+// Closure'lar kendi ortamlarını içerebildiğinden kapsam içeriği yazdırılmaz
+impl std::fmt::Debug for Env {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<env>")
+    }
+}
+
+/// Sınıflar adlarıyla değil kimlikleriyle ayırt edilir: bir fonksiyonun içinde tanımlanan
+/// sınıf aynı adlı global sınıfın yerini almaz, nesneler kendi sınıflarını gösterir.
+#[derive(Debug)]
+pub struct ClassInfo {
+    pub name: String,
+    pub base: Option<Rc<ClassInfo>>,
+    // Alan adı ve başlatıcısı: parametresiz `Value::Func` ya da bytecode motorunda `Value::Closure`
+    pub fields: Vec<(String, Value)>,
+    // Metotlar sınıf tanımındaki ortamı yakalayan `Value::Func` (bytecode motorunda `Value::Closure`)
+    pub methods: HashMap<String, Value>,
+}
+
+impl ClassInfo {
+    /// Metodu bu sınıftan başlayıp üst sınıflara doğru arar; bulunduğu sınıfı da döndürür.
+    pub fn find_method(self: &Rc<Self>, method: &str) -> Option<(Rc<ClassInfo>, Value)> {
+        let mut current = Some(self);
+        while let Some(class) = current {
+            if let Some(func) = class.methods.get(method) {
+                return Some((class.clone(), func.clone()));
+            }
+            current = class.base.as_ref();
+        }
+        None
+    }
+
+    /// Kök sınıftan bu sınıfa doğru tüm alan başlatıcıları.
+    pub fn field_inits(&self) -> Vec<(String, Value)> {
+        let mut fields = match &self.base {
+            Some(base) => base.field_inits(),
+            None => Vec::new(),
+        };
+        fields.extend(self.fields.iter().cloned());
+        fields
+    }
+}
+
+/// Deyimin akışı olağan sıranın dışına çıkardığını bildirir; sinyal iç içe
+/// bloklardan geçerek onu karşılayan döngüye ya da fonksiyon çağrısına kadar taşınır.
+#[derive(Debug)]
+pub enum ControlFlow {
+    Return(Value),
+    Break,
+    Continue,
+}
+
+/// Bir Nova çağrısının Rust yığınında kapladığı yer için ayrılan pay. Ölçülen değerler debug
+/// derlemede 24–57 KB, release derlemede 3–7 KB'dir; gövdesi iç içe bloklarla dolu fonksiyonlar daha fazlasını ister.
+const FRAME_STACK_BYTES: usize = if cfg!(debug_assertions) { 64 * 1024 } else { 8 * 1024 };
+
+/// Ağaç yorumlayıcının çalıştığı iş parçacığının yığını: `FRAMES_MAX` çerçeve ve üst düzey kod için pay.
+pub const STACK_BYTES: usize = (runtime::FRAMES_MAX + 32) * FRAME_STACK_BYTES;
+
+// Yığın sınırına bu kadar yaklaşan çağrı reddedilir; en iç çerçeve ve hata yolu buna sığar
+const STACK_RESERVE_BYTES: usize = 16 * FRAME_STACK_BYTES;
+
+pub struct VM {
+    pub globals: Env,
+    pub runtime: Runtime,
+    pub export_table: HashMap<String, Value>,
+    pub sources: SourceMap,
+    // Açık çağrılar: fonksiyon adı ve çağrının yapıldığı yer
+    call_stack: Vec<(String, Span)>,
+    // En dıştaki çağrının Rust yığınındaki adresi; kullanılan yığın buna göre ölçülür
+    stack_base: usize,
+}
+
+impl VM {
+    pub fn new() -> Self {
+        VM {
+            globals: Env::new(),
+            runtime: Runtime::new(),
+            export_table: HashMap::new(),
+            sources: SourceMap::new(),
+            call_stack: Vec::new(),
+            stack_base: 0,
+        }
+    }
+
+    /// Resolver'ın global sayacağı isimler: önceki çalıştırmaların globalleri ve yerleşikler.
+    pub fn known_globals(&self) -> HashSet<String> {
+        self.globals.names().into_iter().chain(self.runtime.builtins.keys().cloned()).collect()
+    }
+
+    pub fn run(&mut self, program: &Program) -> Result<(), NovaError> {
+        let env = self.globals.clone();
+        for stmt in &program.body {
+            self.eval_stmt(stmt, &env).map_err(|e| self.trace(e))?;
+        }
+        Ok(())
+    }
+
+    fn eval_stmt(&mut self, stmt: &Stmt, env: &Env) -> Result<Option<ControlFlow>, NovaError> {
+        match stmt {
+            Stmt::Let { name, value, .. } => {
+                let val = self.eval_expr(value, env)?;
+                env.define(name, val);
+                Ok(None)
+            }
+            Stmt::Expr(expr) => {
+                self.eval_expr(expr, env)?;
+                Ok(None)
+            }
+            // Async fonksiyonlar şimdilik eşzamanlı çalışır; `await` sonucu hemen döndürür
+            Stmt::Func { name, params, body, .. } | Stmt::AsyncFunc { name, params, body, .. } => {
+                let func = Value::Func {
+                    name: name.clone(),
+                    params: params.clone(),
+                    body: Rc::new(body.clone()),
+                    env: env.clone(),
+                };
+                env.define(name, func);
+                Ok(None)
+            }
+            Stmt::Return(expr, _) => Ok(Some(ControlFlow::Return(self.eval_expr(expr, env)?))),
+            Stmt::Throw(expr, span) => Err(runtime::thrown(self.eval_expr(expr, env)?, *span)),
+            Stmt::For { name, value_name, iterable, body, span } => {
+                let val = self.eval_expr(iterable, env)?;
+                let mut iter = self.iterate(val, *span)?;
+                while let Some((first, second)) = self.iter_next(&mut iter, value_name.is_some(), *span)? {
+                    // Her tur kendi kapsamında çalışır; closure'lar o turun değişkenini yakalar
+                    let loop_env = env.child();
+                    loop_env.define(name, first);
+                    if let Some(value_name) = value_name {
+                        loop_env.define(value_name, second);
+                    }
+                    match self.eval_block(body, &loop_env)? {
+                        Some(ControlFlow::Break) => break,
+                        Some(ControlFlow::Return(val)) => return Ok(Some(ControlFlow::Return(val))),
+                        Some(ControlFlow::Continue) | None => {}
+                    }
+                }
+                Ok(None)
+            }
+            Stmt::Break(_) => Ok(Some(ControlFlow::Break)),
+            Stmt::Continue(_) => Ok(Some(ControlFlow::Continue)),
+            Stmt::Block(body, _) => self.eval_block(body, &env.child()),
+            Stmt::If { cond, then_branch, else_branch, .. } => {
+                if self.eval_expr(cond, env)?.is_truthy() {
+                    self.eval_block(then_branch, &env.child())
+                } else if let Some(else_branch) = else_branch {
+                    self.eval_block(else_branch, &env.child())
+                } else {
+                    Ok(None)
+                }
+            }
+            Stmt::While { cond, body, .. } => {
+                while self.eval_expr(cond, env)?.is_truthy() {
+                    match self.eval_block(body, &env.child())? {
+                        Some(ControlFlow::Break) => break,
+                        Some(ControlFlow::Return(val)) => return Ok(Some(ControlFlow::Return(val))),
+                        Some(ControlFlow::Continue) | None => {}
+                    }
+                }
+                Ok(None)
+            }
+            Stmt::TryCatch { try_block, catches, .. } => self.try_catch(try_block, catches, env),
+            Stmt::Import { name, span } => {
+                // Basit modül yükleyici: 'moduladi.nova' dosyasını yükle ve çalıştır
+                let filename = format!("{}.nova", name);
+                match std::fs::read_to_string(&filename) {
+                    Ok(code) => {
+                        // Lex, parse, semantic, run
+                        let file_id = self.sources.add(&filename, &code);
+                        let mut lexer = crate::lexer::Lexer::new(&code).with_file(file_id);
+                        let tokens = lexer.tokenize()?;
+                        let mut parser = crate::parser::Parser::new(tokens).with_keywords(lexer.keyword_set());
+                        // Modüldeki ilk sözdizimi hatası içe aktarma hatası olarak raporlanır
+                        let mut program = parser.parse_program().map_err(|mut errors| errors.remove(0))?;
+                        crate::semantic::Resolver::new(self.known_globals())
+                            .resolve(&mut program)
+                            .map_err(|mut errors| errors.remove(0))?;
+                        let mut analyzer = crate::semantic::SemanticAnalyzer::new();
+                        analyzer.analyze(&program)?;
+                        self.with_frame("<script>", *span, |vm| vm.run(&program))?;
+                        Ok(None)
+                    }
+                    Err(_) => Err(NovaError::at(NovaErrorKind::RuntimeError, "Modül dosyası bulunamadı", *span)),
+                }
+            }
+            Stmt::Class { name, base, base_slot, fields, methods, span, .. } => {
+                // Üst sınıf, sınıf adı tanımlanmadan önce bulunduğu kapsamda aranır
+                let base = match base {
+                    Some(base_name) => match self.lookup(env, base_name, *base_slot, *span)? {
+                        Value::Class(class) => Some(class),
+                        _ => return Err(NovaError::at(NovaErrorKind::RuntimeError, &runtime::not_a_class(base_name), *span)),
+                    },
+                    None => None,
+                };
+                // Alan başlatıcıları nesne oluşturulurken global ortamda çalışır
+                let fields = fields
+                    .iter()
+                    .map(|(field, init)| {
+                        let func = Value::Func {
+                            name: format!("<alan {}>", field),
+                            params: Vec::new(),
+                            body: Rc::new(vec![Stmt::Return(init.clone(), init.span())]),
+                            env: self.globals.clone(),
+                        };
+                        (field.clone(), func)
+                    })
+                    .collect();
+                let mut table = HashMap::new();
+                for method in methods {
+                    if let Stmt::Func { name, params, body, .. } | Stmt::AsyncFunc { name, params, body, .. } = method {
+                        let func = Value::Func {
+                            name: name.clone(),
+                            params: params.clone(),
+                            body: Rc::new(body.clone()),
+                            env: env.clone(),
+                        };
+                        table.insert(name.clone(), func);
+                    }
+                }
+                let info = ClassInfo { name: name.clone(), base, fields, methods: table };
+                env.define(name, Value::Class(Rc::new(info)));
+                Ok(None)
+            }
+            Stmt::Export { name, decl, span, .. } => {
+                // Export edilen sembolü global export tablosuna kaydet
+                self.eval_stmt(decl, env)?;
+                let val = self.lookup(env, name, None, *span)?;
+                self.export_table.insert(name.clone(), val);
+                Ok(None)
+            }
+            Stmt::TryCatchFinally { try_block, catches, finally_block, .. } => {
+                let result = self.try_catch(try_block, catches, env);
+                // finally her durumda çalışır; oradaki hata ya da return/break/continue
+                // try'ın sonucunun (yakalanmamış hata dahil) yerine geçer
+                match self.eval_block(finally_block, &env.child())? {
+                    Some(flow) => Ok(Some(flow)),
+                    None => result,
+                }
+            }
+        }
+    }
+
+    /// Hatayı türü uyan ilk catch yakalar; türlü catch yalnızca `kind` adı eşleşen hatayı
+    /// alır. Hiçbiri uymazsa hata olduğu gibi döner.
+    fn try_catch(&mut self, try_block: &[Stmt], catches: &[CatchClause], env: &Env) -> Result<Option<ControlFlow>, NovaError> {
+        let e = match self.eval_block(try_block, &env.child()) {
+            Err(e) => e,
+            result => return result,
+        };
+        let kind = e.kind.name();
+        match catches.iter().find(|catch| catch.kind.as_ref().is_none_or(|k| *k == kind)) {
+            Some(catch) => {
+                let catch_env = env.child();
+                catch_env.define(&catch.name, Value::Error(Rc::new(self.trace(e))));
+                self.eval_block(&catch.body, &catch_env)
+            }
+            None => Err(e),
+        }
+    }
+
+    fn eval_expr(&mut self, expr: &Expr, env: &Env) -> Result<Value, NovaError> {
+        match expr {
+            Expr::Int(i, _) => Ok(Value::Int(*i)),
+            Expr::Float(f, _) => Ok(Value::Float(*f)),
+            Expr::String(s, _) => Ok(Value::String(s.clone())),
+            Expr::Bool(b, _) => Ok(Value::Bool(*b)),
+            Expr::Null(_) => Ok(Value::Null),
+            Expr::Identifier(name, slot, span) => self.lookup(env, name, *slot, *span),
+            Expr::BinaryOp { left, op, right, span } => {
+                let l = self.eval_expr(left, env)?;
+                // Kısa devre: sonuç bool'a çevrilmez, belirleyen işlenen döner
+                match op.as_str() {
+                    "&&" if !l.is_truthy() => return Ok(l),
+                    "||" if l.is_truthy() => return Ok(l),
+                    "&&" | "||" => return self.eval_expr(right, env),
+                    _ => {}
+                }
+                let r = self.eval_expr(right, env)?;
+                arith::binary(op, &l, &r).map_err(|e| e.with_span(*span))
+            }
+            Expr::UnaryOp { op, expr, span } => {
+                let val = self.eval_expr(expr, env)?;
+                arith::unary(op, &val).map_err(|e| e.with_span(*span))
+            }
+            Expr::Assign { name, value, slot, span } => {
+                let val = self.eval_expr(value, env)?;
+                let assigned = match slot {
+                    Some(slot) => env.assign_at(*slot, val.clone()),
+                    None => env.assign(name, val.clone()),
+                };
+                if !assigned {
+                    let message = format!("Tanımlanmamış değişken: {}", name);
+                    return Err(NovaError::at(NovaErrorKind::RuntimeError, &message, *span));
+                }
+                Ok(val)
+            }
+            Expr::List(items, _) => {
+                let mut vals = Vec::new();
+                for item in items {
+                    vals.push(self.eval_expr(item, env)?);
+                }
+                Ok(Value::list(vals))
+            }
+            Expr::Map(pairs, span) => {
+                let mut map = HashMap::new();
+                for (k, v) in pairs {
+                    let key_val = self.eval_expr(k, env)?;
+                    let val_val = self.eval_expr(v, env)?;
+                    if let Value::String(s) = key_val {
+                        map.insert(s, val_val);
+                    } else {
+                        return Err(NovaError::at(NovaErrorKind::RuntimeError, "Map anahtarı string olmalı", *span));
+                    }
+                }
+                Ok(Value::map(map))
+            }
+            Expr::Index { collection, index, span } => {
+                let coll_val = self.eval_expr(collection, env)?;
+                let idx_val = self.eval_expr(index, env)?;
+                runtime::get_index(&coll_val, &idx_val).map_err(|msg| NovaError::at(NovaErrorKind::RuntimeError, &msg, *span))
+            }
+            Expr::SetIndex { collection, index, value, span } => {
+                let coll_val = self.eval_expr(collection, env)?;
+                let idx_val = self.eval_expr(index, env)?;
+                let val = self.eval_expr(value, env)?;
+                runtime::set_index(&coll_val, &idx_val, val.clone())
+                    .map_err(|msg| NovaError::at(NovaErrorKind::RuntimeError, &msg, *span))?;
+                Ok(val)
+            }
+            Expr::Range { start, end, inclusive, span } => {
+                let start = self.eval_expr(start, env)?;
+                let end = self.eval_expr(end, env)?;
+                runtime::make_range(&start, &end, *inclusive).map_err(|msg| NovaError::at(NovaErrorKind::RuntimeError, &msg, *span))
+            }
+            Expr::Lambda { params, body, span } => {
+                Ok(Value::Func {
+                    name: "<lambda>".to_string(),
+                    params: params.clone(),
+                    body: Rc::new(vec![Stmt::Return(*body.clone(), *span)]),
+                    env: env.clone(),
+                })
+            }
+            Expr::Call { func, args, span } => {
+                // Yerel ya da global bir tanımla gölgelenmemiş yerleşik fonksiyonlar
+                if let Expr::Identifier(name, _, _) = func.as_ref() {
+                    if env.get(name).is_none() {
+                        if let Some(builtin) = self.runtime.builtins.get(name).copied() {
+                            let arg_vals = self.eval_args(args, env)?;
+                            return Ok(builtin(arg_vals));
+                        }
+                    }
+                }
+                let func_val = self.eval_expr(func, env)?;
+                let arg_vals = self.eval_args(args, env)?;
+                self.call_value(func_val, arg_vals, *span)
+            }
+            Expr::Object { .. } => {
+                // Nesne oluşturma
+                // ...
+                Ok(Value::Null)
+            }
+            Expr::Get { object, name, span } => {
+                let target = self.eval_expr(object, env)?;
+                runtime::get_field(&target, name).map_err(|msg| NovaError::at(NovaErrorKind::RuntimeError, &msg, *span))
+            }
+            Expr::Set { object, name, value, span } => {
+                let target = self.eval_expr(object, env)?;
+                let val = self.eval_expr(value, env)?;
+                runtime::set_field(&target, name, val.clone())
+                    .map_err(|msg| NovaError::at(NovaErrorKind::RuntimeError, &msg, *span))?;
+                Ok(val)
+            }
+            Expr::MethodCall { object, method, args, span } => {
+                // super.metot(): aramaya çağıran metodun üst sınıfından başlanır, `this` aynı kalır
+                if let Expr::Identifier(name, _, _) = object.as_ref() {
+                    if name == "super" {
+                        let base = match env.get("super") {
+                            Some(Value::Class(base)) => base,
+                            _ => return Err(NovaError::at(NovaErrorKind::RuntimeError, "super yalnızca alt sınıf metotlarında kullanılabilir", *span)),
+                        };
+                        let this = env.get("this").unwrap_or(Value::Null);
+                        let arg_vals = self.eval_args(args, env)?;
+                        return self.invoke(this, &base, method, arg_vals, *span);
+                    }
+                }
+                let receiver = self.eval_expr(object, env)?;
+                let arg_vals = self.eval_args(args, env)?;
+                let obj = match &receiver {
+                    Value::Object(obj) => obj.clone(),
+                    _ => return Err(NovaError::at(NovaErrorKind::RuntimeError, "Metot çağrısı yalnızca nesnelerde yapılabilir", *span)),
+                };
+                // Fonksiyon tutan bir alan metotlardan önce gelir
+                let field = obj.borrow().fields.get(method).cloned();
+                if let Some(callee) = field {
+                    return self.call_value(callee, arg_vals, *span);
+                }
+                let class = obj.borrow().class.clone();
+                self.invoke(receiver, &class, method, arg_vals, *span)
+            }
+            Expr::New { class_name, args, span } => {
+                let class = self.lookup(env, class_name, None, *span)?;
+                let arg_vals = self.eval_args(args, env)?;
+                match class {
+                    Value::Class(class) => self.instantiate(&class, arg_vals, *span),
+                    _ => Err(NovaError::at(NovaErrorKind::RuntimeError, &runtime::not_a_class(class_name), *span)),
+                }
+            }
+            Expr::Interpolation { parts, .. } => {
+                let mut out = String::new();
+                for part in parts {
+                    let val = self.eval_expr(part, env)?;
+                    out.push_str(&runtime::format_value(&val));
+                }
+                Ok(Value::String(out))
+            }
+            Expr::Await { expr, .. } => self.eval_expr(expr, env),
+        }
+    }
+
+    fn eval_block(&mut self, block: &[Stmt], env: &Env) -> Result<Option<ControlFlow>, NovaError> {
+        for stmt in block {
+            if let Some(flow) = self.eval_stmt(stmt, env)? {
+                return Ok(Some(flow));
+            }
+        }
+        Ok(None)
+    }
+
+    fn eval_args(&mut self, args: &[Expr], env: &Env) -> Result<Vec<Value>, NovaError> {
+        let mut vals = Vec::with_capacity(args.len());
+        for arg in args {
+            vals.push(self.eval_expr(arg, env)?);
+        }
+        Ok(vals)
+    }
+
+    fn lookup(&self, env: &Env, name: &str, slot: Option<Slot>, span: Span) -> Result<Value, NovaError> {
+        let found = match slot {
+            Some(slot) => env.get_at(slot),
+            None => env.get(name),
+        };
+        match found {
+            Some(val) => Ok(val),
+            None => {
+                let message = format!("Tanımlanmamış değişken: {}", name);
+                Err(NovaError::at(NovaErrorKind::RuntimeError, &message, span))
+            }
+        }
+    }
+
+    fn call_value(&mut self, callee: Value, args: Vec<Value>, span: Span) -> Result<Value, NovaError> {
+        match callee {
+            Value::Func { name, params, body, env } => self.call_func(&name, &params, &body, &env, args, span),
+            Value::Class(class) => self.instantiate(&class, args, span),
+            _ => Err(NovaError::at(NovaErrorKind::RuntimeError, "Fonksiyon çağrısı geçersiz", span)),
+        }
+    }
+
+    fn call_func(
+        &mut self,
+        name: &str,
+        params: &[String],
+        body: &[Stmt],
+        closure: &Env,
+        args: Vec<Value>,
+        span: Span,
+    ) -> Result<Value, NovaError> {
+        if params.len() != args.len() {
+            return Err(NovaError::at(NovaErrorKind::RuntimeError, "Fonksiyon parametre sayısı uyuşmuyor", span));
+        }
+        let env = closure.child();
+        for (param, arg) in params.iter().zip(args) {
+            env.define(param, arg);
+        }
+        // break/continue fonksiyon sınırını geçemez (resolver denetler)
+        match self.with_frame(name, span, |vm| vm.eval_block(body, &env))? {
+            Some(ControlFlow::Return(val)) => Ok(val),
+            _ => Ok(Value::Null),
+        }
+    }
+
+    /// `f`'i `name` çerçevesi açıkken çalıştırır. Hatanın yığını çerçeve kapanmadan doldurulur.
+    fn with_frame<T>(&mut self, name: &str, span: Span, f: impl FnOnce(&mut VM) -> Result<T, NovaError>) -> Result<T, NovaError> {
+        let marker = 0u8;
+        let here = &marker as *const u8 as usize;
+        if self.call_stack.is_empty() {
+            self.stack_base = here;
+        }
+        // Bytecode motoruyla aynı sınır; orada üst düzey betik de bir çerçevedir. Çerçeveleri
+        // ölçülenden büyük olan fonksiyonlar yığın bitmeden aynı hatayı alır.
+        let stack_used = self.stack_base.saturating_sub(here);
+        if self.call_stack.len() + 1 >= runtime::FRAMES_MAX || stack_used + STACK_RESERVE_BYTES >= STACK_BYTES {
+            return Err(self.trace(NovaError::at(NovaErrorKind::RuntimeError, runtime::STACK_OVERFLOW, span)));
+        }
+        self.call_stack.push((name.to_string(), span));
+        let result = f(self).map_err(|e| self.trace(e));
+        self.call_stack.pop();
+        result
+    }
+
+    /// Çağrı yığını henüz boşsa açık çerçevelerden doldurur. En içteki çerçevede hatanın
+    /// kendi konumu, dıştakilerde çağrının yapıldığı satır yazılır; bytecode motoru aynı biçimi üretir.
+    fn trace(&self, e: NovaError) -> NovaError {
+        if !e.stack.is_empty() {
+            return e;
+        }
+        let mut e = e.locate(&self.sources);
+        let mut here = (e.span.map_or(0, |s| s.file_id), e.line);
+        let mut stack = Vec::new();
+        for (name, call) in self.call_stack.iter().rev() {
+            stack.push(stack_frame(name, &self.sources, here.0, here.1));
+            here = (call.file_id, self.sources.line_col(call.file_id, call.start).0);
+        }
+        stack.push(stack_frame("<script>", &self.sources, here.0, here.1));
+        e.stack = stack;
+        e
+    }
+
+    /// for-in için yineleyici. Nesnenin `iter()` metodu varsa onun döndürdüğü değer,
+    /// yoksa `next()` tanımlayan nesnenin kendisi gezilir.
+    fn iterate(&mut self, val: Value, span: Span) -> Result<runtime::Iter, NovaError> {
+        let class = match &val {
+            Value::Object(obj) => obj.borrow().class.clone(),
+            _ => return runtime::Iter::new(&val).map_err(|msg| NovaError::at(NovaErrorKind::RuntimeError, &msg, span)),
+        };
+        if class.find_method("iter").is_some() {
+            let iterator = self.invoke(val, &class, "iter", Vec::new(), span)?;
+            return match iterator {
+                Value::Object(_) => Ok(runtime::Iter::Object(iterator)),
+                other => runtime::Iter::new(&other).map_err(|msg| NovaError::at(NovaErrorKind::RuntimeError, &msg, span)),
+            };
+        }
+        if class.find_method("next").is_some() {
+            return Ok(runtime::Iter::Object(val));
+        }
+        let message = format!("'{}' sınıfı gezilemez: iter() ya da next() metodu yok", class.name);
+        Err(NovaError::at(NovaErrorKind::RuntimeError, &message, span))
+    }
+
+    /// Yineleyiciyi bir adım ilerletir; nesnelerde `next()` çağrılır ve `null` döngüyü bitirir.
+    fn iter_next(&mut self, iter: &mut runtime::Iter, pair: bool, span: Span) -> Result<Option<(Value, Value)>, NovaError> {
+        let object = match iter {
+            runtime::Iter::Object(object) => object.clone(),
+            _ => return Ok(iter.next(pair)),
+        };
+        if pair {
+            let message = "İki değişkenli for döngüsü nesne yineleyicileriyle kullanılamaz";
+            return Err(NovaError::at(NovaErrorKind::RuntimeError, message, span));
+        }
+        let class = match &object {
+            Value::Object(obj) => obj.borrow().class.clone(),
+            _ => unreachable!("nesne yineleyicisi nesne tutar"),
+        };
+        match self.invoke(object, &class, "next", Vec::new(), span)? {
+            Value::Null => Ok(None),
+            item => Ok(Some((item, Value::Null))),
+        }
+    }
+
+    /// `this` bağlanmış olarak metodu çağırır. Metodun içinde `this`/`self` nesneyi,
+    /// `super` ise metodun tanımlandığı sınıfın üst sınıfını gösterir.
+    fn invoke(&mut self, this: Value, class: &Rc<ClassInfo>, method: &str, args: Vec<Value>, span: Span) -> Result<Value, NovaError> {
+        let (owner, func) = match class.find_method(method) {
+            Some(found) => found,
+            None => {
+                let message = format!("'{}' sınıfında '{}' metodu yok", class.name, method);
+                return Err(NovaError::at(NovaErrorKind::RuntimeError, &message, span));
+            }
+        };
+        let (name, params, body, closure) = match func {
+            Value::Func { name, params, body, env } => (name, params, body, env),
+            _ => unreachable!("metot tablosu yalnızca fonksiyon tutar"),
+        };
+        // this/self/super metot gövdesinin hemen üstündeki kapsamda tanımlanır
+        let env = closure.child();
+        env.define("this", this.clone());
+        env.define("self", this);
+        if let Some(base) = &owner.base {
+            env.define("super", Value::Class(base.clone()));
+        }
+        self.call_func(&name, &params, &body, &env, args, span)
+    }
+
+    /// Yeni nesne oluşturur: alanlar kök sınıftan başlayarak ilklendirilir, ardından
+    /// (varsa, kalıtılmış olabilir) `init` yapıcısı çağrılır.
+    fn instantiate(&mut self, class: &Rc<ClassInfo>, args: Vec<Value>, span: Span) -> Result<Value, NovaError> {
+        let mut fields = HashMap::new();
+        for (name, init) in class.field_inits() {
+            // Bytecode motorundaki alan başlatıcı fonksiyonuyla aynı çerçeve
+            let val = self.call_value(init, Vec::new(), span)?;
+            fields.insert(name, val);
+        }
+        let object = Value::Object(Rc::new(RefCell::new(Object { class: class.clone(), fields })));
+        if class.find_method("init").is_some() {
+            self.invoke(object.clone(), class, "init", args, span)?;
+        } else if !args.is_empty() {
+            let message = format!("'{}' sınıfının init yapıcısı yok, argüman verilemez", class.name);
+            return Err(NovaError::at(NovaErrorKind::RuntimeError, &message, span));
+        }
+        Ok(object)
+    }
+}
+
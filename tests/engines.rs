@@ -0,0 +1,54 @@
+// tests/engines.rs
+// NovaLang için motor karşılaştırma testleri: tests/programs altındaki her program
+// ağaç yorumlayıcı ve bytecode motoruyla çalıştırılır, iki çıktı birbiriyle ve
+// programın yanındaki .out dosyasıyla aynı olmalıdır.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const ENGINES: [&str; 2] = ["agac", "bytecode"];
+
+/// Programı verilen motorla çalıştırır; stdout ve stderr birlikte döner. Hata raporu
+/// yalnızca stderr'e yazılır, bu yüzden program ancak stderr boşsa başarıyla bitmelidir.
+fn run(dir: &Path, file: &str, engine: &str) -> (String, bool) {
+    let output = Command::new(env!("CARGO_BIN_EXE_nova"))
+        .arg(format!("--motor={}", engine))
+        .arg(file)
+        .current_dir(dir)
+        .output()
+        .expect("nova çalıştırılamadı");
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    (text, output.status.success() == output.stderr.is_empty())
+}
+
+#[test]
+fn engines_agree_on_every_program() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/programs");
+    let mut programs: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "nova"))
+        // .out dosyası olmayanlar yalnızca import edilen modüllerdir
+        .filter(|path| path.with_extension("out").exists())
+        .collect();
+    programs.sort();
+    assert!(!programs.is_empty(), "tests/programs altında program bulunamadı");
+
+    let mut failures = Vec::new();
+    for path in &programs {
+        let file = path.file_name().unwrap().to_str().unwrap();
+        let expected = fs::read_to_string(path.with_extension("out")).unwrap();
+        for engine in ENGINES {
+            let (actual, status_ok) = run(&dir, file, engine);
+            if actual != expected {
+                failures.push(format!("--- {} ({})\nbeklenen:\n{}\nbulunan:\n{}", file, engine, expected, actual));
+            }
+            if !status_ok {
+                failures.push(format!("--- {} ({}): çıkış kodu hata raporuyla uyuşmuyor", file, engine));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}